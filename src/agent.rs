@@ -0,0 +1,80 @@
+use clap::Parser;
+use spark::core::agent::{self, Agent};
+use std::error::Error;
+use std::io::{self, BufRead};
+use std::path::PathBuf;
+use std::process;
+use std::time::Duration;
+
+#[derive(Parser, Debug)]
+#[command(
+    author,
+    version,
+    about = "Cache an unlocked server key for clients. Like ssh-agent, it \
+             prints the socket for eval and goes into the background."
+)]
+struct Args {
+    #[arg(long, help = "Listen here instead of the default socket")]
+    socket: Option<PathBuf>,
+    #[arg(
+        long,
+        default_value_t = 600,
+        help = "Forget the key after this many idle seconds"
+    )]
+    timeout: u64,
+    #[arg(long, help = "Stay in the foreground, keeping stderr for logs")]
+    foreground: bool,
+    /// Set on the background process by the one the user started
+    #[arg(long, hide = true)]
+    detached: bool,
+}
+
+/// Run the agent again in the background and pass on the line it prints
+/// once its socket is ready, so that `eval $(spark-agent)` returns
+fn spawn() -> Result<(), Box<dyn Error>> {
+    let mut child = process::Command::new(std::env::current_exe()?)
+        .args(std::env::args_os().skip(1))
+        .arg("--detached")
+        .stdout(process::Stdio::piped())
+        .spawn()?;
+    let mut line = String::new();
+    let stdout = child.stdout.take().expect("stdout is piped");
+    io::BufReader::new(stdout).read_line(&mut line)?;
+    if line.is_empty() {
+        // It has already said why on stderr
+        return Err(format!("spark-agent failed: {}", child.wait()?).into());
+    }
+    print!("{line}");
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+
+    env_logger::init();
+    if !args.foreground && !args.detached {
+        return spawn();
+    }
+    agent::harden()?;
+
+    let socket = match args.socket {
+        Some(socket) => socket,
+        None => agent::default_socket()?,
+    };
+    let listener = agent::bind(&socket)?;
+    println!(
+        "{}={}; export {};",
+        agent::SOCKET_ENV,
+        socket.display(),
+        agent::SOCKET_ENV
+    );
+    if args.detached {
+        agent::detach()?;
+    }
+
+    Agent::new(Duration::from_secs(args.timeout))
+        .serve(listener)
+        .await?;
+    Ok(())
+}
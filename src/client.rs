@@ -1,16 +1,177 @@
-use clap::{ArgGroup, Parser, Subcommand, ValueEnum};
+use clap::{ArgGroup, Args as ClapArgs, Parser, Subcommand, ValueEnum};
+use rsa::pkcs8::DecodePublicKey;
+use spark::core::access;
+use spark::core::agent::{self, AgentClient};
 use spark::core::client;
-use spark::Error;
+use spark::core::ssh_agent;
+use spark::core::template::Template;
+use spark::crypto::policy::{Checked, PolicyArgs};
+use spark::crypto::types::{Fingerprint, PublicServerKey, ServerKey};
+use spark::crypto::Encryptor;
+use spark::crypto::{generate, passphrase, pem, ssh};
+use spark::primitives::payloads::{
+    CertificatePayload, CredentialsPayload, EnvPayload, Field, FieldType,
+    FilePayload, KeypairPayload, KeypairType, Payload, RecordPayload,
+    TotpPayload,
+};
+use spark::primitives::secret;
 use spark::BOOTSTRAP_FILE;
-use std::env;
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+use std::os::unix::process::CommandExt;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use toml::Table;
+use x509_cert::der::DateTime;
+use zeroize::Zeroizing;
 
 #[derive(Subcommand, Debug, Clone)]
 enum Put {
+    #[command(about = "A file of any size, streamed in encrypted chunks. \
+                 Its name and mode are restored by get --out.")]
     File { path: PathBuf },
-    Credentials { username: String, password: String },
+    #[command(about = "A username and password. Without a password one is \
+                 generated with --generate, or asked for.")]
+    Credentials {
+        username: String,
+        #[arg(help = "Seen by ps and kept in shell history, so best left out")]
+        password: Option<String>,
+        #[command(flatten)]
+        generate: GenerateArgs,
+    },
+    #[command(about = "A 2FA seed, from the otpauth://totp/ URI in its \
+                 setup QR code. The URI is asked for, or read from stdin.")]
+    Totp,
+    #[command(about = "An unencrypted OpenSSH private key file, for \
+                 ssh-agent to serve")]
+    SshKey { path: PathBuf },
+    #[command(about = "A PEM certificate chain, leaf first. The secret \
+                 expires with the leaf and is described by its subject.")]
+    Certificate {
+        chain: PathBuf,
+        #[arg(long, help = "The leaf's PEM private key, to keep with it")]
+        private_key: Option<PathBuf>,
+    },
+    #[command(about = "Environment variables, from a .env file")]
+    Env { path: PathBuf },
+    #[command(about = "Named fields, in order, some of them concealed")]
+    Record {
+        #[arg(
+            long = "field",
+            required = true,
+            value_name = "NAME[:TYPE][:concealed][=VALUE]",
+            help = "A field of type text, url, email, date, number or \
+                    multiline. Concealed fields without a value are asked \
+                    for."
+        )]
+        fields: Vec<String>,
+    },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+enum Gen {
+    #[command(about = "Create a keypair in the vault and print only its \
+                 public half")]
+    Keypair {
+        name: String,
+        #[arg(value_enum)]
+        scope: Scope,
+        #[arg(long = "type", value_enum)]
+        key_type: KeyType,
+        #[arg(
+            long,
+            help = "Tag the key for ssh-agent and print it as an \
+                    authorized_keys line"
+        )]
+        ssh: bool,
+        #[arg(long = "desc")]
+        desc: Option<String>,
+        #[arg(
+            long,
+            help = "Encrypt to the server key with this fingerprint instead \
+                    of the master key"
+        )]
+        key: Option<String>,
+    },
+}
+
+/// How to generate a password, by the rules of the site it is for
+#[derive(ClapArgs, Debug, Clone)]
+struct GenerateArgs {
+    #[arg(long, conflicts_with = "password", help = "Generate the password")]
+    generate: bool,
+    #[arg(long, requires = "generate", default_value_t = 20)]
+    length: usize,
+    #[arg(
+        long,
+        requires = "generate",
+        value_enum,
+        value_delimiter = ',',
+        help = "The classes to draw from, each used at least once [default: \
+                all]"
+    )]
+    classes: Vec<CharClass>,
+    #[arg(
+        long,
+        requires = "generate",
+        help = "The symbols the site accepts [default: all printable ASCII]"
+    )]
+    symbols: Option<String>,
+    #[arg(
+        long,
+        requires = "generate",
+        help = "Characters to leave out, such as ones easily misread"
+    )]
+    exclude: Option<String>,
+    #[arg(
+        long,
+        requires = "generate",
+        conflicts_with_all = ["length", "classes", "symbols", "exclude"],
+        help = "Generate a passphrase of this many words instead"
+    )]
+    words: Option<usize>,
+    #[arg(long, requires = "words", default_value = "-")]
+    separator: String,
+}
+
+impl GenerateArgs {
+    fn password(&self) -> Result<Zeroizing<String>, spark::Error> {
+        if let Some(words) = self.words {
+            return generate::passphrase(words, &self.separator);
+        }
+        let default = generate::Rules::default();
+        generate::Rules {
+            length: self.length,
+            classes: match self.classes.is_empty() {
+                true => default.classes,
+                false => {
+                    self.classes.iter().map(|c| c.clone().into()).collect()
+                }
+            },
+            symbols: self.symbols.clone().unwrap_or(default.symbols),
+            exclude: self.exclude.clone().unwrap_or_default(),
+        }
+        .password()
+    }
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+enum CharClass {
+    Lower,
+    Upper,
+    Digit,
+    Symbol,
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+enum KeyType {
+    Ed25519,
+    Rsa,
+    X25519,
+    Aes,
 }
 
 #[derive(ValueEnum, Debug, Clone)]
@@ -19,12 +180,143 @@ enum Scope {
     Local,
 }
 
+#[derive(ValueEnum, Debug, Clone)]
+enum Role {
+    Reader,
+    Writer,
+    Admin,
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+enum Tag {
+    Apikey,
+    Publickey,
+    Privatekey,
+    Keypair,
+    Credentials,
+    Other,
+    Sshkey,
+    Certificate,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+enum Admin {
+    #[command(about = "Create a user, or reset their password")]
+    AddUser { user: String },
+    #[command(about = "Give a user a role over a set of secrets")]
+    Grant {
+        user: String,
+        #[arg(value_enum)]
+        role: Role,
+        #[command(flatten)]
+        target: Target,
+    },
+    #[command(about = "Take a role away from a user")]
+    Revoke {
+        user: String,
+        #[command(flatten)]
+        target: Target,
+    },
+    #[command(about = "List every grant on the server")]
+    Grants,
+    #[command(about = "Check every entry in the store for corruption")]
+    Verify,
+}
+
+#[derive(clap::Args, Debug, Clone)]
+#[group(multiple = false)]
+struct Target {
+    #[arg(long, help = "Only secrets whose label starts with this prefix")]
+    prefix: Option<String>,
+    #[arg(long, value_enum, help = "Only secrets with this tag")]
+    tag: Option<Tag>,
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+enum Op {
+    GetMaster,
+    List,
+    Get,
+    Put,
+    Delete,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+enum Token {
+    #[command(about = "Mint an API token for a non-interactive client")]
+    Mint {
+        #[arg(long, help = "Labels the token may access, e.g. \"deploy/*\"")]
+        pattern: String,
+        #[arg(long, value_enum, value_delimiter = ',', required = true)]
+        ops: Vec<Op>,
+        #[arg(long, default_value_t = 3600, help = "Lifetime in seconds")]
+        ttl: u64,
+    },
+    #[command(about = "Revoke an API token by its id")]
+    Revoke { id: String },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+enum Rotate {
+    #[command(about = "Re-encrypt every secret under a new master key")]
+    Start {
+        #[arg(
+            long,
+            default_value = "tty",
+            help = "Where to read the new key's passphrase from"
+        )]
+        new_passphrase_source: String,
+        #[command(flatten)]
+        policy: PolicyArgs,
+    },
+    #[command(about = "Forget the previous master key")]
+    Confirm,
+    #[command(about = "Go back to the previous master key")]
+    Revert,
+    #[command(about = "Save the previous master key to a file")]
+    Previous { path: PathBuf },
+}
+
 #[derive(Subcommand, Debug, Clone)]
 enum Method {
     #[command(about = "List all secrets on the server")]
-    List,
+    List {
+        #[arg(
+            long,
+            value_name = "DURATION",
+            value_parser = parse_duration,
+            help = "Only secrets that expire within this long, such as 30d, \
+                    12h or 2w, soonest first"
+        )]
+        expiring: Option<u64>,
+    },
     #[command(about = "Get a secret(s) from the server")]
-    Get { name: String, id: Option<String> },
+    Get {
+        name: String,
+        id: Option<String>,
+        #[arg(long, help = "Print only this field")]
+        field: Option<String>,
+        #[arg(
+            long,
+            conflicts_with = "field",
+            help = "Write a file secret here, or into this directory under \
+                    its own name, instead of to stdout"
+        )]
+        out: Option<PathBuf>,
+    },
+    #[command(about = "Run a command with the variables of an environment \
+                 secret set")]
+    Exec {
+        name: String,
+        id: Option<String>,
+        #[arg(last = true, required = true)]
+        command: Vec<String>,
+    },
+    #[command(about = "Print a template with its {{ spark \"NAME\" \
+                 [\"FIELD\"] }} placeholders filled in from secrets")]
+    Render { template: PathBuf },
+    #[command(about = "Print the current code of a TOTP secret")]
+    Totp { name: String, id: Option<String> },
     #[command(about = "Store a secret on the server")]
     Put {
         name: String,
@@ -34,7 +326,61 @@ enum Method {
         data: Put,
         #[arg(long = "desc")]
         desc: Option<String>,
+        #[arg(
+            long,
+            help = "Encrypt to the server's public key, which needs neither \
+                    the passphrase nor the right to read secrets"
+        )]
+        write_only: bool,
+        #[arg(
+            long,
+            requires = "write_only",
+            help = "Refuse a public key not signed by this ed25519 key (hex)"
+        )]
+        verifying_key: Option<String>,
+        #[arg(
+            long,
+            help = "Encrypt to the server key with this fingerprint instead \
+                    of the master key"
+        )]
+        key: Option<String>,
     },
+    #[command(about = "Sign a secret deposited by a write-only client, once \
+                 its content is shown and confirmed")]
+    Accept {
+        #[arg(help = "The label or id of the deposit")]
+        name: String,
+        #[arg(
+            long,
+            help = "Accept secrets deposited to the server key with this \
+                    fingerprint instead of the master key"
+        )]
+        key: Option<String>,
+    },
+    #[command(
+        about = "Generate a secret here and store it, so it is never \
+                 written to disk",
+        subcommand
+    )]
+    Gen(Gen),
+    #[command(about = "Serve the SSH keys in the vault to ssh as an agent. \
+                 Like ssh-agent, it prints the socket for eval and goes \
+                 into the background.")]
+    SshAgent {
+        #[arg(long, help = "Listen here instead of the default socket")]
+        socket: Option<PathBuf>,
+        #[arg(long, help = "Stay in the foreground, keeping stderr for logs")]
+        foreground: bool,
+        /// Set on the background process by the one the user started
+        #[arg(long, hide = true)]
+        detached: bool,
+    },
+    #[command(about = "Manage users and their roles", subcommand)]
+    Admin(Admin),
+    #[command(about = "Manage API tokens", subcommand)]
+    Token(Token),
+    #[command(about = "Rotate the server's master key", subcommand)]
+    Rotate(Rotate),
 }
 
 #[derive(Parser, Debug)]
@@ -49,10 +395,103 @@ struct Args {
     addr: Option<String>,
     #[arg(long, help = "Connect to known peer from bootstrap file")]
     peer: Option<String>,
+    #[arg(long, help = "Log in as this user (prompts for a password)")]
+    user: Option<String>,
+    #[arg(
+        long,
+        conflicts_with = "user",
+        help = "Log in with the API token in this file (or set SPARK_TOKEN)"
+    )]
+    token_file: Option<PathBuf>,
+    #[arg(
+        long,
+        default_value = "tty",
+        help = "Where to read the master key passphrase from: tty, env:VAR, \
+                fd:N, file:PATH or cmd:PROGRAM"
+    )]
+    passphrase_source: String,
     #[command(subcommand)]
     method: Method,
 }
 
+impl From<Scope> for secret::Scope {
+    fn from(scope: Scope) -> Self {
+        match scope {
+            Scope::Public => secret::Scope::Public,
+            Scope::Local => secret::Scope::Local,
+        }
+    }
+}
+
+impl From<Tag> for secret::Tag {
+    fn from(tag: Tag) -> Self {
+        match tag {
+            Tag::Apikey => secret::Tag::APIKey,
+            Tag::Publickey => secret::Tag::PublicKey,
+            Tag::Privatekey => secret::Tag::PrivateKey,
+            Tag::Keypair => secret::Tag::Keypair,
+            Tag::Credentials => secret::Tag::Credentials,
+            Tag::Other => secret::Tag::Other,
+            Tag::Sshkey => secret::Tag::SshKey,
+            Tag::Certificate => secret::Tag::Certificate,
+        }
+    }
+}
+
+impl From<CharClass> for generate::Class {
+    fn from(class: CharClass) -> Self {
+        match class {
+            CharClass::Lower => generate::Class::Lower,
+            CharClass::Upper => generate::Class::Upper,
+            CharClass::Digit => generate::Class::Digit,
+            CharClass::Symbol => generate::Class::Symbol,
+        }
+    }
+}
+
+impl From<KeyType> for KeypairType {
+    fn from(key_type: KeyType) -> Self {
+        match key_type {
+            KeyType::Ed25519 => KeypairType::Ed25519,
+            KeyType::Rsa => KeypairType::Rsa,
+            KeyType::X25519 => KeypairType::X25519,
+            KeyType::Aes => KeypairType::Aes,
+        }
+    }
+}
+
+impl From<Role> for access::Role {
+    fn from(role: Role) -> Self {
+        match role {
+            Role::Reader => access::Role::Reader,
+            Role::Writer => access::Role::Writer,
+            Role::Admin => access::Role::Admin,
+        }
+    }
+}
+
+impl From<Op> for access::Op {
+    fn from(op: Op) -> Self {
+        match op {
+            Op::GetMaster => access::Op::GetMaster,
+            Op::List => access::Op::List,
+            Op::Get => access::Op::Get,
+            Op::Put => access::Op::Put,
+            Op::Delete => access::Op::Delete,
+        }
+    }
+}
+
+impl From<Target> for access::Target {
+    fn from(target: Target) -> Self {
+        match (target.prefix, target.tag) {
+            (Some(prefix), _) => access::Target::Prefix(prefix),
+            (None, Some(tag)) => access::Target::Tag(tag.into()),
+            (None, None) => access::Target::All,
+        }
+    }
+}
+
 fn bootstrap(name: &str) -> Result<String, Box<dyn std::error::Error>> {
     let s = fs::read_to_string(BOOTSTRAP_FILE)?;
     let file = s.parse::<Table>()?;
@@ -60,26 +499,941 @@ fn bootstrap(name: &str) -> Result<String, Box<dyn std::error::Error>> {
         .get("peers")
         .expect("malformed bootstrap file: see README")
         .get(name)
-        .expect(format!("could not find bootstrap peer \"{name}\"").as_str());
-    println!("peer: {peer:?}");
+        .unwrap_or_else(|| panic!("could not find bootstrap peer \"{name}\""));
     Ok(peer.to_string())
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// Ask on the terminal, so that neither the prompt nor the answer mixes
+/// with stdout
+fn read_password(prompt: &str) -> io::Result<String> {
+    rpassword::prompt_password(prompt)
+}
+
+/// Use a running agent if there is one, handing it the master key on first
+/// use. Otherwise fetch the master key, or the key with `fingerprint`, and
+/// unlock it here. The agent only ever holds the master key, so other keys
+/// are always unlocked here.
+async fn encryptor(
+    client: &client::Client,
+    source: &str,
+    fingerprint: Option<&Fingerprint>,
+) -> Result<Box<dyn Encryptor>, Box<dyn std::error::Error>> {
+    let provider = passphrase::parse(source)?;
+    let master = match AgentClient::find() {
+        Some(agent) => {
+            let held = agent.key()?;
+            if held.is_some() && held.as_ref() == fingerprint {
+                return Ok(Box::new(agent));
+            }
+            // The agent may still hold a key that has been rotated out, so
+            // what it holds is checked against the current master key
+            let master = client.master_key(None).await?;
+            let current = master.fingerprint()?;
+            let wanted = fingerprint.or(current.as_ref());
+            match held {
+                Some(held) if wanted == Some(&held) => {
+                    return Ok(Box::new(agent))
+                }
+                None if wanted.is_some() && wanted == current.as_ref() => {
+                    agent.unlock(master, provider.as_ref())?;
+                    return Ok(Box::new(agent));
+                }
+                _ if wanted == current.as_ref() => Some(master),
+                _ => None,
+            }
+        }
+        None => None,
+    };
+    let key = match master {
+        Some(master) => master,
+        None => client.master_key(fingerprint).await?,
+    };
+    Ok(Box::new(ServerKey::unlock(key, provider.as_ref())?))
+}
+
+/// Parse a `--key` argument
+fn fingerprint(
+    key: Option<&str>,
+) -> Result<Option<Fingerprint>, Box<dyn std::error::Error>> {
+    Ok(key.map(str::parse).transpose()?)
+}
+
+/// Fetch the server's public key for a write-only put, pinned to
+/// `verifying_key` if given
+async fn public_key(
+    client: &client::Client,
+    fingerprint: Option<&Fingerprint>,
+    verifying_key: Option<&str>,
+) -> Result<PublicServerKey, Box<dyn std::error::Error>> {
+    let key = client.public_key(fingerprint).await?;
+    let signer = key.verifying.map(hex::encode);
+    match (verifying_key, &signer) {
+        (Some(pin), Some(signer)) if pin.eq_ignore_ascii_case(signer) => {}
+        (Some(_), _) => {
+            return Err("the server's public key is not signed by the \
+                        expected key"
+                .into())
+        }
+        (None, Some(signer)) => {
+            eprintln!("encrypting to the server key signed by {signer}")
+        }
+        (None, None) => eprintln!("warning: the server key is not signed"),
+    }
+    Ok(key)
+}
+
+/// Write a secret to stdout. `Debug` would redact it.
+fn print_payload(payload: &Payload) -> io::Result<()> {
+    let mut out = io::stdout().lock();
+    match payload {
+        Payload::Generic(p) => out.write_all(p.secret()),
+        Payload::Credentials(p) => writeln!(
+            out,
+            "service: {}\nusername: {}\npassword: {}",
+            p.service(),
+            p.username(),
+            p.password()
+        ),
+        Payload::Keypair(p) => writeln!(
+            out,
+            "type: {:?}\npublic: {}\nprivate: {}",
+            p.key_type(),
+            hex::encode(p.public()),
+            hex::encode(p.private())
+        ),
+        Payload::Record(p) => p.fields().iter().try_for_each(|f| {
+            // Indent the lines of multiline values under their name
+            writeln!(out, "{}: {}", f.name(), f.value().replace('\n', "\n  "))
+        }),
+        Payload::Env(p) => write!(out, "{}", *Zeroizing::new(p.to_dotenv())),
+        Payload::Certificate(p) => {
+            let pem = p.to_pem().map_err(io::Error::other)?;
+            write!(out, "{pem}")?;
+            match p.private_key() {
+                Some(key) => write!(out, "{key}"),
+                None => Ok(()),
+            }
+        }
+        Payload::File(p) => writeln!(
+            out,
+            "name: {}\nsize: {}\nmode: {:o}",
+            p.name(),
+            p.size(),
+            p.mode()
+        ),
+        Payload::Totp(p) => writeln!(
+            out,
+            "algorithm: {:?}\ndigits: {}\nperiod: {}\nseed: {}",
+            p.algorithm(),
+            p.digits(),
+            p.period(),
+            data_encoding::BASE32_NOPAD.encode(p.seed())
+        ),
+        Payload::Opaque(p) => {
+            let shown = p.display().map_err(io::Error::other)?;
+            write!(out, "{}", *shown)
+        }
+    }
+}
+
+/// A time in seconds since the epoch, as RFC 3339
+fn date(secs: u64) -> String {
+    DateTime::from_unix_duration(Duration::from_secs(secs))
+        .map(|d| d.to_string())
+        .unwrap_or_else(|_| secs.to_string())
+}
+
+/// What a secret holds, without its secret parts, for a person to check
+fn summary(payload: &Payload) -> String {
+    match payload {
+        Payload::Generic(p) => format!("{} bytes", p.secret().len()),
+        Payload::Credentials(p) => {
+            format!("credentials for {} at {}", p.username(), p.service())
+        }
+        Payload::Keypair(p) => format!(
+            "{:?} keypair, public {}",
+            p.key_type(),
+            hex::encode(p.public())
+        ),
+        Payload::Totp(p) => format!(
+            "TOTP seed, {:?}, {} digits every {}s",
+            p.algorithm(),
+            p.digits(),
+            p.period()
+        ),
+        Payload::Record(p) => {
+            let names: Vec<&str> =
+                p.fields().iter().map(|f| f.name()).collect();
+            format!("record of {}", names.join(", "))
+        }
+        Payload::Env(p) => {
+            let names: Vec<&str> = p.vars().iter().map(|v| v.name()).collect();
+            format!("environment of {}", names.join(", "))
+        }
+        Payload::File(p) => format!(
+            "file {}, {} bytes, mode {:o}",
+            p.name(),
+            p.size(),
+            p.mode()
+        ),
+        Payload::Certificate(p) => format!(
+            "certificate for {}, issued by {}",
+            p.subject().unwrap_or_default(),
+            p.issuer().unwrap_or_default()
+        ),
+        Payload::Opaque(p) => format!(
+            "{} version {}, {} bytes",
+            p.type_id(),
+            p.version(),
+            p.body().len()
+        ),
+    }
+}
+
+/// Ask a yes or no question, on stderr so that stdout stays clean
+fn confirm(question: &str) -> io::Result<bool> {
+    eprint!("{question} [y/N] ");
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer)?;
+    Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
+}
+
+/// Stream a file's content to `out`, or to stdout. A file is written beside
+/// its destination and only moved into place once all of it is read and
+/// authenticated.
+async fn get_file(
+    client: &client::Client,
+    header: &secret::Header,
+    file: &FilePayload,
+    out: Option<PathBuf>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut opener = file.opener()?;
+    let chunks = file.chunks();
+    let mut index = 0;
+    let mut read = 0u64;
+    let mut open = |chunk: &[u8]| {
+        index += 1;
+        let plain = opener.open(chunk, index == chunks)?;
+        read += plain.len() as u64;
+        Ok::<_, spark::Error>(plain)
+    };
+    let check = |read: u64| match read == file.size() {
+        true => Ok(()),
+        false => Err(format!(
+            "read {read} bytes of {}, expected {}",
+            file.name(),
+            file.size()
+        )),
+    };
+
+    let Some(out) = out else {
+        let mut stdout = io::stdout().lock();
+        client
+            .get_file(header, chunks, |chunk| {
+                Ok(stdout.write_all(&open(chunk)?)?)
+            })
+            .await?;
+        stdout.flush()?;
+        return Ok(check(read)?);
+    };
+
+    // Checked on decode too, but a name that is more than a name would
+    // replace or climb out of the directory it is joined to
+    let name = Path::new(file.name());
+    if name.file_name() != Some(name.as_os_str()) {
+        return Err(format!("refusing file name {:?}", file.name()).into());
+    }
+    let target = match out.is_dir() {
+        true => out.join(name),
+        false => out,
+    };
+    let partial = target.with_file_name(format!(
+        ".{}.partial",
+        target
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("spark")
+    ));
+    let mut writer = io::BufWriter::new(
+        fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .mode(0o600)
+            .open(&partial)?,
+    );
+    let result = client
+        .get_file(header, chunks, |chunk| Ok(writer.write_all(&open(chunk)?)?))
+        .await
+        .map_err(|e| e.into())
+        .and_then(|_| Ok(writer.flush()?))
+        .and_then(|_| Ok(check(read)?));
+    drop(writer);
+    if let Err(e) = result {
+        let _ = fs::remove_file(&partial);
+        return Err(e);
+    }
+    // Never setuid, setgid or sticky, whatever the manifest says
+    let mode = file.mode() & 0o777;
+    fs::set_permissions(&partial, fs::Permissions::from_mode(mode))?;
+    fs::rename(&partial, &target)?;
+    eprintln!("wrote {} bytes to {}", file.size(), target.display());
+    Ok(())
+}
+
+/// Write a single field of a secret to stdout
+fn print_field(
+    payload: &Payload,
+    name: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    println!("{}", payload.field(name)?);
+    Ok(())
+}
+
+const DAY: u64 = 24 * 60 * 60;
+
+/// Parse a duration such as 30d, 12h, 2w, 90m or 45s into seconds. A bare
+/// number is days.
+fn parse_duration(s: &str) -> Result<u64, String> {
+    let (number, unit) = match s.find(|c: char| !c.is_ascii_digit()) {
+        Some(i) => s.split_at(i),
+        None => (s, "d"),
+    };
+    let unit = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => DAY,
+        "w" => 7 * DAY,
+        _ => {
+            return Err(format!("unknown unit \"{unit}\", use s, m, h, d or w"))
+        }
+    };
+    let number: u64 = number
+        .parse()
+        .map_err(|_| format!("invalid duration \"{s}\""))?;
+    number
+        .checked_mul(unit)
+        .ok_or_else(|| format!("duration \"{s}\" is too long"))
+}
+
+/// Build a field from `NAME[:TYPE][:concealed][=VALUE]`
+fn parse_field(spec: &str) -> Result<Field, Box<dyn std::error::Error>> {
+    let (spec, value) = match spec.split_once('=') {
+        Some((spec, value)) => (spec, Some(value)),
+        None => (spec, None),
+    };
+    let mut parts = spec.split(':');
+    let name = parts.next().unwrap_or_default();
+    let mut field_type = FieldType::Text;
+    let mut concealed = false;
+    for part in parts {
+        match part {
+            "concealed" => concealed = true,
+            part => field_type = part.parse()?,
+        }
+    }
+    // Concealed values are best kept off the command line
+    let value = match (value, concealed) {
+        (Some(value), _) => Zeroizing::new(value.to_string()),
+        (None, true) => Zeroizing::new(read_password(&format!("{name}: "))?),
+        (None, false) => {
+            return Err(format!("field {name} needs a value").into())
+        }
+    };
+    Ok(Field::new(name, field_type, &value, concealed)?)
+}
+
+/// Find a secret by its name, and the start of its id when names clash
+async fn find(
+    client: &client::Client,
+    name: &str,
+    id: Option<&str>,
+) -> Result<secret::Header, Box<dyn std::error::Error>> {
+    Ok(client
+        .list()
+        .await?
+        .into_iter()
+        .find(|h| {
+            h.label == name
+                && id.is_none_or(|id| h.id.to_string().starts_with(id))
+        })
+        .ok_or_else(|| format!("no such secret \"{name}\""))?)
+}
+
+async fn admin(
+    client: &client::Client,
+    cmd: Admin,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match cmd {
+        Admin::AddUser { user } => {
+            let password = read_password(&format!("Password for {user}: "))?;
+            client.add_user(&user, &password).await?;
+        }
+        Admin::Grant { user, role, target } => {
+            let grant = access::Grant::new(&user, role.into(), target.into());
+            client.grant(&grant).await?;
+        }
+        Admin::Revoke { user, target } => {
+            // The role is ignored when revoking
+            let grant =
+                access::Grant::new(&user, access::Role::Reader, target.into());
+            client.revoke(&grant).await?;
+        }
+        Admin::Grants => {
+            client.grants().await?.iter().for_each(|g| {
+                println!("{}\t{:?}\t{:?}", g.user, g.role, g.target)
+            });
+        }
+        Admin::Verify => {
+            let report = client.verify().await?;
+            report.problems.iter().for_each(|p| {
+                println!(
+                    "{}\t{}\t{:?}\t{}",
+                    p.tree,
+                    hex::encode(&p.key),
+                    p.fault,
+                    p.detail
+                )
+            });
+            println!(
+                "{} ok, {} unchecked, {} problems",
+                report.ok,
+                report.unchecked,
+                report.problems.len()
+            );
+            if !report.problems.is_empty() {
+                return Err("store has problems".into());
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn tokens(
+    client: &client::Client,
+    cmd: Token,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match cmd {
+        Token::Mint { pattern, ops, ttl } => {
+            let ops: Vec<access::Op> = ops.into_iter().map(Op::into).collect();
+            println!("{}", client.mint_token(&pattern, &ops, ttl).await?);
+        }
+        Token::Revoke { id } => client.revoke_token(&id).await?,
+    }
+    Ok(())
+}
+
+async fn gen(
+    client: &client::Client,
+    cmd: Gen,
+    source: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match cmd {
+        Gen::Keypair {
+            name,
+            scope,
+            key_type,
+            ssh,
+            desc,
+            key,
+        } => {
+            let key_type = KeypairType::from(key_type);
+            if ssh && !ssh::is_ssh(key_type) {
+                return Err(
+                    format!("{key_type:?} keys are not SSH keys").into()
+                );
+            }
+            let key = fingerprint(key.as_deref())?;
+            let enc = encryptor(client, source, key.as_ref()).await?;
+            let pair = KeypairPayload::generate(key_type)?;
+            let public = match pair.key_type() {
+                _ if ssh => format!("{}\n", ssh::public_line(&pair, &name)?),
+                KeypairType::Rsa => pem::public_to_pem(
+                    &rsa::RsaPublicKey::from_public_key_der(pair.public())
+                        .map_err(|e| format!("reading public key: {e}"))?,
+                )?,
+                KeypairType::Aes => String::new(),
+                _ => format!("{}\n", hex::encode(pair.public())),
+            };
+            let header = secret::Header::new(
+                &name,
+                desc.as_deref(),
+                Some(match ssh {
+                    true => secret::Tag::SshKey,
+                    false => secret::Tag::Keypair,
+                }),
+                0,
+                scope.into(),
+            )?;
+            client
+                .put(&enc.encrypt(secret::Secret {
+                    secret: Payload::Keypair(pair),
+                    header,
+                })?)
+                .await?;
+            // Symmetric keys have nothing that is safe to show
+            print!("{public}");
+        }
+    }
+    Ok(())
+}
+
+async fn rotate(
+    client: &client::Client,
+    cmd: Rotate,
+    source: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match cmd {
+        Rotate::Start {
+            new_passphrase_source,
+            policy,
+        } => {
+            let policy = policy.policy()?;
+            let old = encryptor(client, source, None).await?;
+            let new = passphrase::parse(&new_passphrase_source)?;
+            let new = Checked {
+                provider: new.as_ref(),
+                policy: &policy,
+            };
+            let n = client.rotate(old.as_ref(), ServerKey::new(), &new).await?;
+            // The agent would keep handing out the old key
+            if let Some(agent) = AgentClient::find() {
+                agent.lock()?;
+            }
+            println!(
+                "rotated {n} secrets. Check them, then run `rotate confirm` \
+                 (or `rotate revert`)."
+            );
+        }
+        Rotate::Confirm => client.confirm_rotation().await?,
+        Rotate::Revert => {
+            client.revert_rotation().await?;
+            if let Some(agent) = AgentClient::find() {
+                agent.lock()?;
+            }
+        }
+        Rotate::Previous { path } => {
+            let key = client.previous_master_key().await?;
+            fs::write(path, key.to_bytes()?)?;
+        }
+    }
+    Ok(())
+}
+
+/// Run `ssh-agent` again in the background and pass on the line it prints
+/// once its socket is ready, so that `eval $(client ssh-agent)` returns. The
+/// passphrase and password are asked for by the background process, while
+/// it still has the terminal.
+fn spawn_ssh_agent() -> Result<(), Box<dyn std::error::Error>> {
+    let mut child = process::Command::new(std::env::current_exe()?)
+        .args(std::env::args_os().skip(1))
+        .arg("--detached")
+        .stdout(process::Stdio::piped())
+        .spawn()?;
+    let mut line = String::new();
+    let stdout = child.stdout.take().expect("stdout is piped");
+    io::BufReader::new(stdout).read_line(&mut line)?;
+    if line.is_empty() {
+        // It has already said why on stderr
+        return Err(format!("ssh-agent failed: {}", child.wait()?).into());
+    }
+    print!("{line}");
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Args::parse();
-    println!("{args:#?}");
 
     env_logger::init();
 
+    if let Method::SshAgent {
+        foreground: false,
+        detached: false,
+        ..
+    } = args.method
+    {
+        return spawn_ssh_agent();
+    }
+
     let addr = match (args.addr, args.peer) {
         (Some(addr), None) => addr,
         (None, Some(peer)) => bootstrap(&peer)?,
         (_, _) => unreachable!(),
     };
 
-    println!("addr: {addr:?}");
+    let mut client = client::Client::init(&addr)?;
+    if let Some(user) = args.user {
+        let password = read_password("Password: ")?;
+        client = client.login(&user, &password);
+    }
+    let token = match args.token_file {
+        Some(path) => Some(fs::read_to_string(path)?.trim().to_string()),
+        None => std::env::var("SPARK_TOKEN").ok(),
+    };
+    if let Some(token) = token {
+        client = client.with_token(&token);
+    }
+
+    match args.method {
+        Method::List {
+            expiring: Some(within),
+        } => {
+            let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+            let mut headers: Vec<secret::Header> = client
+                .list()
+                .await?
+                .into_iter()
+                .filter(|h| {
+                    h.expiration != 0
+                        && h.expiration <= now.saturating_add(within)
+                })
+                .collect();
+            headers.sort_by_key(|h| h.expiration);
+            for h in headers {
+                let left = match h.expiration.checked_sub(now) {
+                    Some(left) => format!("{}d left", left / DAY),
+                    None => "EXPIRED".to_string(),
+                };
+                println!(
+                    "{}\t{}\t{}\t{left}",
+                    h.id,
+                    h.label,
+                    date(h.expiration)
+                );
+            }
+        }
+        Method::List { expiring: None } => {
+            client.list().await?.iter().for_each(|h| {
+                println!(
+                    "{}\t{}\t{}",
+                    h.id,
+                    h.label,
+                    h.desc.as_deref().unwrap_or_default()
+                )
+            });
+        }
+        Method::Get {
+            name,
+            id,
+            field,
+            out,
+        } => {
+            let header = find(&client, &name, id.as_deref()).await?;
+            let enc = client.get(&header).await?;
+            let key =
+                encryptor(&client, &args.passphrase_source, enc.key.as_ref())
+                    .await?;
+            let payload = key.decrypt(enc)?.secret;
+            match (payload, field) {
+                (Payload::File(file), None) => {
+                    get_file(&client, &header, &file, out).await?
+                }
+                (_, _) if out.is_some() => {
+                    return Err(format!("\"{name}\" is not a file").into())
+                }
+                (payload, Some(field)) => print_field(&payload, &field)?,
+                (payload, None) => print_payload(&payload)?,
+            }
+        }
+        Method::Exec { name, id, command } => {
+            let header = find(&client, &name, id.as_deref()).await?;
+            let enc = client.get(&header).await?;
+            let key =
+                encryptor(&client, &args.passphrase_source, enc.key.as_ref())
+                    .await?;
+            let Payload::Env(env) = key.decrypt(enc)?.secret else {
+                return Err(format!("\"{name}\" is not an environment").into());
+            };
+            let mut cmd = process::Command::new(&command[0]);
+            cmd.args(&command[1..]);
+            for var in env.vars() {
+                cmd.env(var.name(), var.value());
+            }
+            // Become the command, so it has the terminal, gets signals and
+            // exits with its own status
+            let err = cmd.exec();
+            return Err(format!("running {}: {err}", command[0]).into());
+        }
+        Method::Render { template } => {
+            let text = fs::read_to_string(&template)?;
+            let template = Template::parse(&text)?;
+            let names = template.names();
+            let secrets = client.get_labeled(&names).await?;
+
+            // Unlock each key the secrets are under only once
+            let mut keys: Vec<(Option<Fingerprint>, Box<dyn Encryptor>)> =
+                vec![];
+            let mut payloads = HashMap::new();
+            for (name, enc) in names.iter().zip(secrets) {
+                let i = match keys.iter().position(|(f, _)| *f == enc.key) {
+                    Some(i) => i,
+                    None => {
+                        let key = encryptor(
+                            &client,
+                            &args.passphrase_source,
+                            enc.key.as_ref(),
+                        )
+                        .await?;
+                        keys.push((enc.key, key));
+                        keys.len() - 1
+                    }
+                };
+                payloads
+                    .insert(name.to_string(), keys[i].1.decrypt(enc)?.secret);
+            }
+            io::stdout().write_all(template.render(&payloads)?.as_bytes())?;
+        }
+        Method::Totp { name, id } => {
+            let header = find(&client, &name, id.as_deref()).await?;
+            let enc = client.get(&header).await?;
+            let key =
+                encryptor(&client, &args.passphrase_source, enc.key.as_ref())
+                    .await?;
+            let Payload::Totp(totp) = key.decrypt(enc)?.secret else {
+                return Err(format!("\"{name}\" is not a TOTP secret").into());
+            };
+            let (code, remaining) = totp.code()?;
+            println!("{code}\t{remaining}s");
+        }
+        Method::Put {
+            name,
+            scope,
+            data,
+            desc,
+            write_only,
+            verifying_key,
+            key,
+        } => {
+            let key = fingerprint(key.as_deref())?;
+            // The content of a file, sealed as it is read
+            let mut content = None;
+            let (payload, tag) = match data {
+                Put::File { path } => {
+                    let name = path
+                        .file_name()
+                        .and_then(|n| n.to_str())
+                        .ok_or_else(|| format!("no file name in {path:?}"))?;
+                    let reader = fs::File::open(&path)?;
+                    let meta = reader.metadata()?;
+                    if !meta.is_file() {
+                        return Err(format!("{path:?} is not a file").into());
+                    }
+                    let file = FilePayload::new(
+                        name,
+                        meta.len(),
+                        meta.permissions().mode() & 0o7777,
+                    )?;
+                    content = Some(file.seal(io::BufReader::new(reader))?);
+                    (Payload::File(file), None)
+                }
+                Put::Credentials {
+                    username,
+                    password,
+                    generate,
+                } => {
+                    let password = match password {
+                        Some(password) => Zeroizing::new(password),
+                        None if generate.generate => generate.password()?,
+                        None => {
+                            let password = Zeroizing::new(read_password(
+                                &format!("Password for {name}: "),
+                            )?);
+                            let again = Zeroizing::new(read_password(
+                                "Confirm password: ",
+                            )?);
+                            if password != again {
+                                return Err("Passwords do not match".into());
+                            }
+                            password
+                        }
+                    };
+                    (
+                        Payload::Credentials(CredentialsPayload::new(
+                            &name, &username, &password,
+                        )),
+                        Some(secret::Tag::Credentials),
+                    )
+                }
+                Put::Totp => {
+                    // Never an argument, where ps and shell history see it
+                    let uri = Zeroizing::new(match io::stdin().is_terminal() {
+                        true => read_password(&format!("URI for {name}: "))?,
+                        false => {
+                            let mut line = String::new();
+                            io::stdin().read_line(&mut line)?;
+                            line
+                        }
+                    });
+                    (Payload::Totp(TotpPayload::from_uri(&uri)?), None)
+                }
+                Put::Certificate { chain, private_key } => {
+                    let chain = fs::read_to_string(chain)?;
+                    let private_key = private_key
+                        .map(fs::read_to_string)
+                        .transpose()?
+                        .map(Zeroizing::new);
+                    (
+                        Payload::Certificate(CertificatePayload::from_pem(
+                            &chain,
+                            private_key.as_deref().map(|k| k.as_str()),
+                        )?),
+                        Some(secret::Tag::Certificate),
+                    )
+                }
+                Put::Env { path } => {
+                    let text = Zeroizing::new(fs::read_to_string(path)?);
+                    (Payload::Env(EnvPayload::from_dotenv(&text)?), None)
+                }
+                Put::Record { fields } => {
+                    let fields = fields
+                        .iter()
+                        .map(|spec| parse_field(spec))
+                        .collect::<Result<Vec<Field>, _>>()?;
+                    (Payload::Record(RecordPayload::new(fields)?), None)
+                }
+                Put::SshKey { path } => {
+                    let text = Zeroizing::new(fs::read_to_string(path)?);
+                    (
+                        Payload::Keypair(ssh::from_openssh(&text)?),
+                        Some(secret::Tag::SshKey),
+                    )
+                }
+            };
+            // Certificates carry their own expiry and description
+            let (expiration, desc) = match &payload {
+                Payload::Certificate(cert) => {
+                    (cert.not_after()?, desc.or(Some(cert.subject()?)))
+                }
+                _ => (0, desc),
+            };
+            let header = secret::Header::new(
+                &name,
+                desc.as_deref(),
+                tag,
+                expiration,
+                scope.into(),
+            )?;
+            let secret = secret::Secret {
+                secret: payload,
+                header,
+            };
+            let enc = match write_only {
+                true => {
+                    public_key(&client, key.as_ref(), verifying_key.as_deref())
+                        .await?
+                        .encrypt(secret)?
+                }
+                false => {
+                    encryptor(&client, &args.passphrase_source, key.as_ref())
+                        .await?
+                        .encrypt(secret)?
+                }
+            };
+            match content {
+                Some(chunks) => client.put_file(&enc, chunks, false).await?,
+                None => client.put(&enc).await?,
+            }
+        }
+        Method::Accept { name, key } => {
+            // The agent only decrypts checked secrets, so unlock here
+            let provider = passphrase::parse(&args.passphrase_source)?;
+            let key = fingerprint(key.as_deref())?;
+            let key = ServerKey::unlock(
+                client.master_key(key.as_ref()).await?,
+                provider.as_ref(),
+            )?;
+            if key.verifying_key().is_none() {
+                return Err("the key does not sign, so there is nothing to \
+                            accept"
+                    .into());
+            }
+            let fingerprint = key.fingerprint()?;
+
+            // Signed secrets may share the label, and are not candidates
+            let (mut deposits, mut signed) = (vec![], false);
+            for header in client.list().await? {
+                if header.label != name && header.id.to_string() != name {
+                    continue;
+                }
+                let enc = client.get(&header).await?;
+                if enc.signature.is_some() {
+                    signed = true;
+                } else if enc.key.is_none_or(|k| k == fingerprint) {
+                    deposits.push(enc);
+                }
+            }
+            let enc = match deposits.len() {
+                0 if signed => {
+                    return Err(format!(
+                        "{name} is already signed, and there is no deposit \
+                         to accept"
+                    )
+                    .into())
+                }
+                0 => return Err(format!("no deposit named {name}").into()),
+                1 => deposits.remove(0),
+                _ => {
+                    let ids: Vec<String> = deposits
+                        .iter()
+                        .map(|d| d.header.id.to_string())
+                        .collect();
+                    return Err(format!(
+                        "several deposits are named {name}, give one of their \
+                         ids: {}",
+                        ids.join(", ")
+                    )
+                    .into());
+                }
+            };
 
-    let client = client::Client::init(&addr)?;
+            let secret = key.open_deposit(enc)?;
+            let header = &secret.header;
+            eprintln!("id: {}", header.id);
+            eprintln!("label: {}", header.label);
+            if let Some(desc) = &header.desc {
+                eprintln!("desc: {desc}");
+            }
+            eprintln!("tag: {:?}", header.tag.clone().unwrap_or_default());
+            eprintln!("created: {}", date(header.creation as u64 / 1000));
+            if header.expiration != 0 {
+                eprintln!("expires: {}", date(header.expiration));
+            }
+            eprintln!("content: {}", summary(&secret.secret));
+            if !confirm("Sign this secret as your own?")? {
+                return Err("not accepted".into());
+            }
+            let (id, label) = (header.id.to_string(), header.label.clone());
+            client.put(&key.accept(secret)?).await?;
+            println!("accepted {id}\t{label}");
+        }
+        Method::SshAgent {
+            socket, detached, ..
+        } => {
+            let key = encryptor(&client, &args.passphrase_source, None).await?;
+            let socket = match socket {
+                Some(socket) => socket,
+                None => ssh_agent::default_socket()?,
+            };
+            let listener = agent::bind(&socket)?;
+            println!(
+                "{}={}; export {};",
+                ssh_agent::SOCKET_ENV,
+                socket.display(),
+                ssh_agent::SOCKET_ENV
+            );
+            if detached {
+                agent::detach()?;
+            }
+            ssh_agent::serve(listener, &client, key.as_ref()).await?;
+        }
+        Method::Gen(cmd) => gen(&client, cmd, &args.passphrase_source).await?,
+        Method::Admin(cmd) => admin(&client, cmd).await?,
+        Method::Token(cmd) => tokens(&client, cmd).await?,
+        Method::Rotate(cmd) => {
+            rotate(&client, cmd, &args.passphrase_source).await?
+        }
+    }
 
     Ok(())
 }
@@ -0,0 +1,291 @@
+use crate::primitives::secret::{Header, Tag};
+use crate::Error;
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A role held by a user. Roles are ordered, and each one includes the
+/// permissions of the roles below it.
+#[derive(
+    Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Clone, Copy,
+)]
+pub enum Role {
+    /// May list and read secrets
+    Reader,
+
+    /// May also store and delete secrets
+    Writer,
+
+    /// May also manage users and grants
+    Admin,
+}
+
+/// An operation on the keyserver that is subject to access control
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum Op {
+    GetMaster,
+    List,
+    Get,
+    Put,
+    Delete,
+    Admin,
+}
+
+/// The set of secrets a grant applies to
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub enum Target {
+    /// Every secret on the server
+    All,
+
+    /// Secrets whose label starts with the given prefix
+    Prefix(String),
+
+    /// Secrets with the given tag
+    Tag(Tag),
+}
+
+/// A role assigned to a user over a set of secrets
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct Grant {
+    pub user: String,
+    pub role: Role,
+    pub target: Target,
+}
+
+/// The restrictions carried by an API token. A token can never do more than
+/// the user who minted it.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct TokenClaims {
+    /// The public half of the token, used to look it up and revoke it
+    pub id: String,
+
+    /// The user who minted the token
+    pub issuer: String,
+
+    /// Labels the token may touch. `*` matches any run of characters.
+    pub pattern: String,
+
+    /// Operations the token may perform. Never includes `Op::Admin`.
+    pub ops: Vec<Op>,
+
+    /// Epoch in seconds after which the token is rejected
+    pub expiration: u64,
+}
+
+/// The caller on whose behalf a `Protocol` operation is run
+#[derive(Debug, PartialEq, Clone)]
+pub enum Identity {
+    /// A connection that has not logged in
+    Anonymous,
+
+    /// An authenticated user
+    User(String),
+
+    /// A non-interactive client holding an API token
+    Token(TokenClaims),
+
+    /// The server process itself. Never assigned to a network session.
+    Local,
+}
+
+impl Role {
+    /// Whether this role allows the given operation
+    pub fn permits(&self, op: Op) -> bool {
+        match op {
+            Op::GetMaster | Op::List | Op::Get => true,
+            Op::Put | Op::Delete => *self >= Role::Writer,
+            Op::Admin => *self == Role::Admin,
+        }
+    }
+}
+
+impl Target {
+    /// Whether a secret falls under this target
+    pub fn matches(&self, header: &Header) -> bool {
+        match self {
+            Target::All => true,
+            Target::Prefix(p) => header.label.starts_with(p.as_str()),
+            Target::Tag(t) => header.tag.as_ref() == Some(t),
+        }
+    }
+}
+
+impl TokenClaims {
+    /// Check the token's own restrictions, independent of its issuer's roles
+    fn permits(&self, op: Op, header: Option<&Header>) -> Result<(), Error> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        if now >= self.expiration {
+            return Err(Error::Denied(format!("token {} expired", self.id)));
+        }
+        if op == Op::Admin || !self.ops.contains(&op) {
+            return Err(Error::Denied(format!(
+                "token {} may not perform {op:?}",
+                self.id
+            )));
+        }
+        match header {
+            Some(h) if !glob_match(&self.pattern, &h.label) => {
+                Err(Error::Denied(format!(
+                    "token {} may not access {}",
+                    self.id, h.label
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Match `text` against a pattern in which `*` matches any run of characters
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    // There is always a first part, possibly empty
+    let first = parts.next().unwrap_or_default();
+    let mut rest = match text.strip_prefix(first) {
+        Some(r) => r,
+        None => return false,
+    };
+
+    let parts: Vec<&str> = parts.collect();
+    if parts.is_empty() {
+        return rest.is_empty();
+    }
+    for (i, part) in parts.iter().enumerate() {
+        if i == parts.len() - 1 {
+            return rest.ends_with(part);
+        }
+        match rest.find(part) {
+            Some(at) => rest = &rest[at + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+impl Grant {
+    pub fn new(user: &str, role: Role, target: Target) -> Self {
+        Self {
+            user: user.to_owned(),
+            role,
+            target,
+        }
+    }
+}
+
+/// Decide whether `caller`, holding `grants`, may perform `op`. Operations
+/// on a single secret pass its `header`; operations that do not concern a
+/// particular secret pass `None` and require a grant over every secret for
+/// `Op::Admin`, or any grant at all otherwise. Token callers are checked
+/// against both the token and the `grants` of its issuer.
+pub fn authorize(
+    caller: &Identity,
+    grants: &[Grant],
+    op: Op,
+    header: Option<&Header>,
+) -> Result<(), Error> {
+    let user = match caller {
+        Identity::Local => return Ok(()),
+        Identity::Anonymous => {
+            return Err(Error::Denied("not logged in".to_string()))
+        }
+        Identity::User(u) => u,
+        Identity::Token(claims) => {
+            claims.permits(op, header)?;
+            &claims.issuer
+        }
+    };
+
+    let allowed = grants
+        .iter()
+        .filter(|g| &g.user == user && g.role.permits(op))
+        .any(|g| match (header, op) {
+            (Some(h), _) => g.target.matches(h),
+            (None, Op::Admin) => g.target == Target::All,
+            (None, _) => true,
+        });
+
+    if allowed {
+        Ok(())
+    } else {
+        Err(Error::Denied(format!("{user} may not perform {op:?}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::secret::Scope;
+
+    fn header(label: &str, tag: Option<Tag>) -> Header {
+        Header::new(label, None, tag, 0, Scope::Public).unwrap()
+    }
+
+    #[test]
+    fn test_roles() {
+        let grants = vec![
+            Grant::new("ci", Role::Reader, Target::All),
+            Grant::new("matt", Role::Writer, Target::Prefix("prod/".into())),
+            Grant::new("root", Role::Admin, Target::All),
+        ];
+        let ci = Identity::User("ci".to_string());
+        let matt = Identity::User("matt".to_string());
+        let root = Identity::User("root".to_string());
+        let prod = header("prod/db", None);
+        let dev = header("dev/db", None);
+
+        assert!(authorize(&ci, &grants, Op::Get, Some(&prod)).is_ok());
+        assert!(authorize(&ci, &grants, Op::Put, Some(&prod)).is_err());
+        assert!(authorize(&matt, &grants, Op::Put, Some(&prod)).is_ok());
+        assert!(authorize(&matt, &grants, Op::Get, Some(&dev)).is_err());
+        assert!(authorize(&matt, &grants, Op::Admin, None).is_err());
+        assert!(authorize(&root, &grants, Op::Admin, None).is_ok());
+        assert!(
+            authorize(&Identity::Anonymous, &grants, Op::List, None).is_err()
+        );
+    }
+
+    #[test]
+    fn test_tag_target() {
+        let grants =
+            vec![Grant::new("ops", Role::Writer, Target::Tag(Tag::APIKey))];
+        let ops = Identity::User("ops".to_string());
+
+        let key = header("stripe", Some(Tag::APIKey));
+        let creds = header("stripe", Some(Tag::Credentials));
+        assert!(authorize(&ops, &grants, Op::Delete, Some(&key)).is_ok());
+        assert!(authorize(&ops, &grants, Op::Delete, Some(&creds)).is_err());
+    }
+
+    #[test]
+    fn test_token() {
+        let grants = vec![Grant::new("matt", Role::Writer, Target::All)];
+        let mut claims = TokenClaims {
+            id: "deploy".to_string(),
+            issuer: "matt".to_string(),
+            pattern: "deploy/*".to_string(),
+            ops: vec![Op::List, Op::Get],
+            expiration: u64::MAX,
+        };
+        let token = Identity::Token(claims.clone());
+        let deploy = header("deploy/db", None);
+        let other = header("prod/db", None);
+
+        assert!(authorize(&token, &grants, Op::Get, Some(&deploy)).is_ok());
+        assert!(authorize(&token, &grants, Op::Get, Some(&other)).is_err());
+        assert!(authorize(&token, &grants, Op::Put, Some(&deploy)).is_err());
+        assert!(authorize(&token, &[], Op::Get, Some(&deploy)).is_err());
+
+        claims.expiration = 0;
+        let expired = Identity::Token(claims);
+        assert!(authorize(&expired, &grants, Op::Get, Some(&deploy)).is_err());
+    }
+
+    #[test]
+    fn test_glob() {
+        assert!(glob_match("deploy/*", "deploy/db"));
+        assert!(glob_match("*", "anything"));
+        assert!(glob_match("a*b*c", "aXXbYYc"));
+        assert!(glob_match("exact", "exact"));
+        assert!(!glob_match("exact", "exactly"));
+        assert!(!glob_match("deploy/*", "prod/deploy/db"));
+        assert!(!glob_match("*.key", "server.pem"));
+    }
+}
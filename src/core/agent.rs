@@ -0,0 +1,535 @@
+//! A local agent that holds an unlocked `ServerKey`, so that the passphrase
+//! is only asked for (and Argon2 only run) once per session. Clients talk to
+//! it over a Unix socket with length-prefixed bincode messages.
+
+use crate::crypto::passphrase::PassphraseProvider;
+use crate::crypto::types::{EncServerKey, Fingerprint, ServerKey};
+use crate::crypto::Encryptor;
+use crate::primitives::payloads::Payload;
+use crate::primitives::secret::{EncSecret, Header, Secret};
+use crate::Error;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{self, Read, Write};
+use std::os::unix::fs::{DirBuilderExt, MetadataExt};
+use std::os::unix::io::AsRawFd;
+use std::os::unix::net::UnixStream;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::UnixListener;
+use zeroize::{Zeroize, Zeroizing};
+
+/// The environment variable clients look in for the agent's socket
+pub const SOCKET_ENV: &str = "SPARK_AGENT_SOCK";
+
+/// Frames larger than this are rejected rather than allocated
+const MAX_FRAME: u32 = 16 << 20;
+
+/// How long a client has to send its request once connected
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Not `Debug`, since an unlock request carries a passphrase
+#[derive(Serialize, Deserialize)]
+pub enum Request {
+    /// Decrypt `key` with `passphrase` and hold it
+    Unlock {
+        key: Box<EncServerKey>,
+        passphrase: String,
+    },
+    Encrypt {
+        payload: Payload,
+        header: Header,
+    },
+    Decrypt(EncSecret),
+    /// Forget the key now
+    Lock,
+    Status,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Response {
+    Ok,
+    /// The agent holds no key. Send `Request::Unlock` first.
+    Locked,
+    Encrypted(EncSecret),
+    Decrypted(Payload),
+    Status {
+        unlocked: bool,
+        /// Seconds until the key is forgotten
+        remaining: u64,
+        /// The fingerprint of the key held
+        key: Option<Fingerprint>,
+    },
+    Error(String),
+}
+
+/// A passphrase sent by a client along with `Request::Unlock`
+struct Given(Zeroizing<String>);
+
+/// The agent's state
+pub struct Agent {
+    key: Option<ServerKey>,
+    timeout: Duration,
+    last_used: Instant,
+}
+
+/// A connection to a running agent
+pub struct AgentClient {
+    path: PathBuf,
+}
+
+/// The socket used when `SPARK_AGENT_SOCK` is not set
+pub fn default_socket() -> Result<PathBuf, Error> {
+    runtime_socket("agent")
+}
+
+/// A socket named for `name` in this user's private runtime directory
+pub(crate) fn runtime_socket(name: &str) -> Result<PathBuf, Error> {
+    Ok(runtime_dir()?.join(format!("{name}.sock")))
+}
+
+/// `$XDG_RUNTIME_DIR/spark`, created private to this user. There is no
+/// fallback to a shared directory such as /tmp, where another user could
+/// take the socket's name first and be sent passphrases.
+fn runtime_dir() -> Result<PathBuf, Error> {
+    let base = std::env::var_os("XDG_RUNTIME_DIR")
+        .filter(|dir| !dir.is_empty())
+        .ok_or_else(|| {
+            Error::Fail(
+                "$XDG_RUNTIME_DIR is not set: give a socket in a directory \
+                 only this user can use"
+                    .to_string(),
+            )
+        })?;
+    let dir = PathBuf::from(base).join("spark");
+    match fs::DirBuilder::new().mode(0o700).create(&dir) {
+        Err(e) if e.kind() != io::ErrorKind::AlreadyExists => {
+            return Err(e.into())
+        }
+        _ => {}
+    }
+    private_dir(&dir)?;
+    Ok(dir)
+}
+
+/// Check that `dir` is a directory of this user's that no one else can use
+fn private_dir(dir: &Path) -> Result<(), Error> {
+    let meta = fs::symlink_metadata(dir)?;
+    if !meta.is_dir()
+        || meta.uid() != unsafe { libc::getuid() }
+        || meta.mode() & 0o077 != 0
+    {
+        return Err(Error::Fail(format!(
+            "{} must be a directory only this user can use (mode 0700)",
+            dir.display()
+        )));
+    }
+    Ok(())
+}
+
+/// Refuse a connection from, or to, a process of another user
+pub(crate) fn check_peer(socket: &impl AsRawFd) -> Result<(), Error> {
+    let mut cred: libc::ucred = unsafe { std::mem::zeroed() };
+    let mut len = std::mem::size_of::<libc::ucred>() as libc::socklen_t;
+    let ret = unsafe {
+        libc::getsockopt(
+            socket.as_raw_fd(),
+            libc::SOL_SOCKET,
+            libc::SO_PEERCRED,
+            &mut cred as *mut libc::ucred as *mut libc::c_void,
+            &mut len,
+        )
+    };
+    if ret != 0 {
+        return Err(io::Error::last_os_error().into());
+    }
+    if cred.uid != unsafe { libc::getuid() } {
+        return Err(Error::Denied(format!("peer is uid {}", cred.uid)));
+    }
+    Ok(())
+}
+
+/// Listen on a socket only this user can use, in a directory only this user
+/// can use, replacing one left behind by an agent that has gone
+pub fn bind(path: &Path) -> Result<UnixListener, Error> {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    private_dir(dir)?;
+    if path.exists() {
+        if UnixStream::connect(path).is_ok() {
+            return Err(Error::Fail(format!(
+                "an agent is already listening on {}",
+                path.display()
+            )));
+        }
+        fs::remove_file(path)?;
+    }
+    // Created without permissions for others, so there is no moment when
+    // they could connect
+    let umask = unsafe { libc::umask(0o177) };
+    let listener = UnixListener::bind(path);
+    unsafe { libc::umask(umask) };
+    let listener = listener?;
+    info!("listening on {}", path.display());
+    Ok(listener)
+}
+
+/// Keep the agent's memory out of swap and core dumps, and stop other
+/// processes of the same user from attaching to it
+pub fn harden() -> Result<(), Error> {
+    if unsafe { libc::prctl(libc::PR_SET_DUMPABLE, 0, 0, 0, 0) } != 0 {
+        return Err(std::io::Error::last_os_error().into());
+    }
+    // Usually limited by RLIMIT_MEMLOCK, so only worth a warning
+    if unsafe { libc::mlockall(libc::MCL_CURRENT | libc::MCL_FUTURE) } != 0 {
+        warn!("mlockall: {}", std::io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Leave the terminal's session and point stdin, stdout and stderr at
+/// /dev/null, so that whoever started the process can go on without it
+pub fn detach() -> Result<(), Error> {
+    io::stdout().flush()?;
+    if unsafe { libc::setsid() } < 0 {
+        return Err(io::Error::last_os_error().into());
+    }
+    let null = fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open("/dev/null")?;
+    for fd in 0..3 {
+        if unsafe { libc::dup2(null.as_raw_fd(), fd) } < 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+    }
+    Ok(())
+}
+
+fn write_frame<T: Serialize>(w: &mut impl Write, msg: &T) -> Result<(), Error> {
+    let buf = Zeroizing::new(bincode::serialize(msg)?);
+    w.write_all(&(buf.len() as u32).to_be_bytes())?;
+    w.write_all(&buf)?;
+    Ok(())
+}
+
+fn read_frame<T: for<'de> Deserialize<'de>>(
+    r: &mut impl Read,
+) -> Result<T, Error> {
+    let mut len = [0u8; 4];
+    r.read_exact(&mut len)?;
+    let len = u32::from_be_bytes(len);
+    if len > MAX_FRAME {
+        return Err(Error::Fail(format!("agent frame of {len} bytes")));
+    }
+    let mut buf = Zeroizing::new(vec![0u8; len as usize]);
+    r.read_exact(&mut buf)?;
+    Ok(bincode::deserialize(&buf)?)
+}
+
+impl PassphraseProvider for Given {
+    fn passphrase(&self, _: bool) -> Result<Zeroizing<String>, Error> {
+        Ok(self.0.clone())
+    }
+}
+
+impl Agent {
+    /// A locked agent that forgets its key after `timeout` without requests
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            key: None,
+            timeout,
+            last_used: Instant::now(),
+        }
+    }
+
+    /// Forget the key if it has been idle for too long
+    fn expire(&mut self) {
+        if self.key.is_some() && self.last_used.elapsed() >= self.timeout {
+            info!("idle for {:?}, locking", self.timeout);
+            self.key = None;
+        }
+    }
+
+    pub fn handle(&mut self, req: Request) -> Response {
+        self.expire();
+        match req {
+            Request::Status => {
+                let remaining = match self.key {
+                    Some(_) => {
+                        self.timeout.saturating_sub(self.last_used.elapsed())
+                    }
+                    None => Duration::ZERO,
+                };
+                let key = self.key.as_ref().map(ServerKey::fingerprint);
+                match key.transpose() {
+                    Ok(key) => Response::Status {
+                        unlocked: self.key.is_some(),
+                        remaining: remaining.as_secs(),
+                        key,
+                    },
+                    Err(e) => Response::Error(e.to_string()),
+                }
+            }
+            Request::Lock => {
+                self.key = None;
+                Response::Ok
+            }
+            Request::Unlock { key, passphrase } => {
+                match ServerKey::unlock(
+                    *key,
+                    &Given(Zeroizing::new(passphrase)),
+                ) {
+                    Ok(key) => {
+                        self.key = Some(key);
+                        self.last_used = Instant::now();
+                        Response::Ok
+                    }
+                    Err(e) => Response::Error(e.to_string()),
+                }
+            }
+            Request::Encrypt { payload, header } => self.with_key(|key| {
+                key.encrypt(Secret {
+                    secret: payload,
+                    header,
+                })
+                .map(Response::Encrypted)
+            }),
+            Request::Decrypt(sec) => self.with_key(|key| {
+                key.decrypt(sec).map(|s| Response::Decrypted(s.secret))
+            }),
+        }
+    }
+
+    /// Run `f` with the key, if the agent holds one, and reset the idle timer
+    fn with_key<F>(&mut self, f: F) -> Response
+    where
+        F: FnOnce(&ServerKey) -> Result<Response, Error>,
+    {
+        self.last_used = Instant::now();
+        match &self.key {
+            Some(key) => {
+                f(key).unwrap_or_else(|e| Response::Error(e.to_string()))
+            }
+            None => Response::Locked,
+        }
+    }
+
+    /// Serve requests on a socket from `bind`, one connection at a time,
+    /// until the process is killed
+    pub async fn serve(mut self, listener: UnixListener) -> Result<(), Error> {
+        loop {
+            // Wake up when the key is due to expire, even with no clients
+            let accept = match &self.key {
+                Some(_) => {
+                    let wait =
+                        self.timeout.saturating_sub(self.last_used.elapsed());
+                    match tokio::time::timeout(wait, listener.accept()).await {
+                        Ok(conn) => conn,
+                        Err(_) => {
+                            self.expire();
+                            continue;
+                        }
+                    }
+                }
+                None => listener.accept().await,
+            };
+            let (mut stream, _) = accept?;
+            if let Err(e) = check_peer(&stream) {
+                warn!("refusing connection: {e}");
+                continue;
+            }
+
+            let req = tokio::time::timeout(REQUEST_TIMEOUT, async {
+                let len = stream.read_u32().await?;
+                if len > MAX_FRAME {
+                    return Err(Error::Fail(format!("request of {len} bytes")));
+                }
+                let mut buf = Zeroizing::new(vec![0u8; len as usize]);
+                stream.read_exact(&mut buf).await?;
+                Ok(bincode::deserialize::<Request>(&buf)?)
+            })
+            .await;
+            let res = match req {
+                Ok(Ok(req)) => self.handle(req),
+                Ok(Err(e)) => Response::Error(e.to_string()),
+                Err(_) => {
+                    warn!("client sent no request, dropping it");
+                    continue;
+                }
+            };
+            let mut out = Zeroizing::new(Vec::new());
+            write_frame(&mut *out, &res)?;
+            if let Err(e) = stream.write_all(&out).await {
+                warn!("writing response: {e}");
+            }
+        }
+    }
+}
+
+impl AgentClient {
+    /// Connect to the agent at `$SPARK_AGENT_SOCK`, or at the default
+    /// socket, if one is running
+    pub fn find() -> Option<Self> {
+        let path = match std::env::var_os(SOCKET_ENV) {
+            Some(path) => PathBuf::from(path),
+            None => default_socket().ok()?,
+        };
+        match UnixStream::connect(&path) {
+            Ok(_) => Some(Self { path }),
+            Err(_) => None,
+        }
+    }
+
+    pub fn request(&self, req: &Request) -> Result<Response, Error> {
+        let mut stream = UnixStream::connect(&self.path)?;
+        // Passphrases are only sent to an agent run by this user
+        check_peer(&stream)?;
+        write_frame(&mut stream, req)?;
+        match read_frame(&mut stream)? {
+            Response::Error(e) => Err(Error::Fail(format!("agent: {e}"))),
+            Response::Locked => Err(Error::Fail("agent is locked".into())),
+            res => Ok(res),
+        }
+    }
+
+    /// Whether the agent currently holds a key
+    pub fn is_unlocked(&self) -> Result<bool, Error> {
+        Ok(self.key()?.is_some())
+    }
+
+    /// The fingerprint of the key the agent holds, if any
+    pub fn key(&self) -> Result<Option<Fingerprint>, Error> {
+        match self.request(&Request::Status)? {
+            Response::Status { key, .. } => Ok(key),
+            res => Err(Error::Fail(format!("unexpected response {res:?}"))),
+        }
+    }
+
+    /// Hand the agent an encrypted key and the passphrase to unlock it
+    pub fn unlock(
+        &self,
+        key: EncServerKey,
+        passphrase: &dyn PassphraseProvider,
+    ) -> Result<(), Error> {
+        let mut phrase = passphrase.passphrase(false)?;
+        let req = Request::Unlock {
+            key: Box::new(key),
+            passphrase: std::mem::take(&mut *phrase),
+        };
+        let res = self.request(&req);
+        if let Request::Unlock { mut passphrase, .. } = req {
+            passphrase.zeroize();
+        }
+        res.map(|_| ())
+    }
+
+    pub fn lock(&self) -> Result<(), Error> {
+        self.request(&Request::Lock)?;
+        Ok(())
+    }
+}
+
+impl Encryptor for AgentClient {
+    fn fingerprint(&self) -> Result<Fingerprint, Error> {
+        self.key()?
+            .ok_or_else(|| Error::Fail("agent is locked".to_string()))
+    }
+
+    fn encrypt(&self, sec: Secret) -> Result<EncSecret, Error> {
+        let req = Request::Encrypt {
+            payload: sec.secret,
+            header: sec.header,
+        };
+        match self.request(&req)? {
+            Response::Encrypted(enc) => Ok(enc),
+            res => Err(Error::Fail(format!("unexpected response {res:?}"))),
+        }
+    }
+
+    fn decrypt(&self, sec: EncSecret) -> Result<Secret, Error> {
+        let header = sec.header.clone();
+        match self.request(&Request::Decrypt(sec))? {
+            Response::Decrypted(payload) => Ok(Secret {
+                secret: payload,
+                header,
+            }),
+            res => Err(Error::Fail(format!("unexpected response {res:?}"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::passphrase::Env;
+    use crate::primitives::payloads::GenericPayload;
+    use crate::primitives::secret::Scope;
+    use std::os::unix::fs::PermissionsExt;
+
+    #[test]
+    fn test_agent() {
+        std::env::set_var("SPARK_TEST_AGENT", "agent pw");
+        let key = ServerKey::new()
+            .lock(&Env("SPARK_TEST_AGENT".to_string()))
+            .unwrap();
+        let header =
+            Header::new("agent", None, None, 0, Scope::Public).unwrap();
+        let encrypt = || Request::Encrypt {
+            payload: Payload::Generic(GenericPayload::new(vec![1, 2, 3])),
+            header: header.clone(),
+        };
+
+        let mut agent = Agent::new(Duration::from_secs(60));
+        assert!(matches!(agent.handle(encrypt()), Response::Locked));
+        let wrong = Request::Unlock {
+            key: Box::new(key.clone()),
+            passphrase: "wrong".to_string(),
+        };
+        assert!(matches!(agent.handle(wrong), Response::Error(_)));
+        let right = Request::Unlock {
+            key: Box::new(key),
+            passphrase: "agent pw".to_string(),
+        };
+        assert!(matches!(agent.handle(right), Response::Ok));
+
+        let enc = match agent.handle(encrypt()) {
+            Response::Encrypted(enc) => enc,
+            res => panic!("unexpected {res:?}"),
+        };
+        assert!(matches!(
+            agent.handle(Request::Decrypt(enc)),
+            Response::Decrypted(Payload::Generic(_))
+        ));
+
+        // An expired key is forgotten on the next request
+        agent.timeout = Duration::ZERO;
+        assert!(matches!(agent.handle(encrypt()), Response::Locked));
+    }
+
+    #[tokio::test]
+    async fn test_bind() {
+        let dir = std::env::temp_dir().join("spark_test_bind");
+        let _ = fs::remove_dir_all(&dir);
+        fs::DirBuilder::new().mode(0o755).create(&dir).unwrap();
+        let path = dir.join("agent.sock");
+
+        // Not in a directory others can reach
+        assert!(bind(&path).is_err());
+        fs::set_permissions(&dir, fs::Permissions::from_mode(0o700)).unwrap();
+        let listener = bind(&path).unwrap();
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert!(bind(&path).is_err());
+
+        let stream = UnixStream::connect(&path).unwrap();
+        let (accepted, _) = listener.accept().await.unwrap();
+        check_peer(&stream).unwrap();
+        check_peer(&accepted).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
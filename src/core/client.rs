@@ -1,30 +1,65 @@
-use crate::protocol_capnp;
+use super::access::{Grant, Op};
+use super::store::Report;
+use super::wire::{
+    op_to_wire, read_grant, read_header, read_master_key, read_public_key,
+    read_report, read_secret, write_grant, write_header, write_master_key,
+    write_secret,
+};
+use crate::crypto::passphrase::PassphraseProvider;
+use crate::crypto::types::{
+    EncServerKey, Fingerprint, PublicServerKey, ServerKey,
+};
+use crate::crypto::Encryptor;
+use crate::primitives::secret::{EncSecret, Header};
 use crate::protocol_capnp::keyserver;
 use crate::Error;
-use crate::BOOTSTRAP_FILE;
-use capnp::capability::Promise;
-use capnp_rpc::{pry, rpc_twoparty_capnp, twoparty, RpcSystem};
-use futures::{AsyncReadExt, TryFutureExt};
-use log::{debug, info};
+use capnp_rpc::{rpc_twoparty_capnp, twoparty, RpcSystem};
+use futures::{AsyncReadExt, Future};
 use std::net;
 use std::net::ToSocketAddrs;
 
 pub struct Client {
     addr: net::SocketAddr,
+
+    /// Username and password to log in with, if any
+    credentials: Option<(String, String)>,
+
+    /// API token to log in with, if any. Takes precedence over credentials.
+    token: Option<String>,
 }
 
 impl Client {
     pub fn init(addr: &str) -> Result<Self, Error> {
         Ok(Self {
             addr: addr.to_socket_addrs()?.next().expect("invalid addrress"),
+            credentials: None,
+            token: None,
         })
     }
 
-    pub async fn request(&self) -> Result<(), Box<dyn std::error::Error>> {
+    /// Log in as `user` on every subsequent request
+    pub fn login(mut self, user: &str, password: &str) -> Self {
+        self.credentials = Some((user.to_string(), password.to_string()));
+        self
+    }
+
+    /// Log in with an API token on every subsequent request
+    pub fn with_token(mut self, token: &str) -> Self {
+        self.token = Some(token.to_string());
+        self
+    }
+
+    /// Connect to the server, log in if credentials were given, and run `f`
+    /// against the resulting session
+    async fn session<F, Fut, T>(&self, f: F) -> Result<T, Error>
+    where
+        F: FnOnce(keyserver::Client) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
         tokio::task::LocalSet::new()
             .run_until(async move {
                 let stream = tokio::net::TcpStream::connect(&self.addr).await?;
-                //stream.set_nodelay(true);
+                stream.set_nodelay(true)?;
                 let (reader, writer) =
                     tokio_util::compat::TokioAsyncReadCompatExt::compat(stream)
                         .split();
@@ -43,8 +78,358 @@ impl Client {
 
                 tokio::task::spawn_local(rpc_system);
 
-                Ok(())
+                let client = match (&self.token, &self.credentials) {
+                    (Some(token), _) => {
+                        let mut req = client.login_token_request();
+                        req.get().set_token(token);
+                        req.send().promise.await?.get()?.get_session()?
+                    }
+                    (None, Some((user, password))) => {
+                        let mut req = client.login_request();
+                        req.get().set_user(user);
+                        req.get().set_password(password);
+                        req.send().promise.await?.get()?.get_session()?
+                    }
+                    (None, None) => client,
+                };
+
+                f(client).await
             })
             .await
     }
+
+    /// Get the headers of every secret visible to this user
+    pub async fn list(&self) -> Result<Vec<Header>, Error> {
+        self.session(|client| async move {
+            let res = client.list_request().send().promise.await?;
+            res.get()?.get_keys()?.iter().map(read_header).collect()
+        })
+        .await
+    }
+
+    /// Get the server's encrypted master key, or another key it holds by
+    /// fingerprint
+    pub async fn master_key(
+        &self,
+        fingerprint: Option<&Fingerprint>,
+    ) -> Result<EncServerKey, Error> {
+        self.session(|client| async move {
+            let mut req = client.get_master_request();
+            if let Some(fingerprint) = fingerprint {
+                req.get().set_fingerprint(fingerprint.as_bytes());
+            }
+            let res = req.send().promise.await?;
+            read_master_key(res.get()?.get_key()?)
+        })
+        .await
+    }
+
+    /// Get the public half of the server's master key, or of another key
+    /// by fingerprint, checked against its own signature. Needs only the
+    /// right to put secrets.
+    pub async fn public_key(
+        &self,
+        fingerprint: Option<&Fingerprint>,
+    ) -> Result<PublicServerKey, Error> {
+        self.session(|client| async move {
+            let mut req = client.get_public_request();
+            if let Some(fingerprint) = fingerprint {
+                req.get().set_fingerprint(fingerprint.as_bytes());
+            }
+            let res = req.send().promise.await?;
+            let key = read_public_key(res.get()?.get_key()?)?;
+            let actual = key.fingerprint()?;
+            if fingerprint.is_some_and(|f| *f != actual) {
+                return Err(Error::Fail(
+                    "server sent the wrong public key".to_string(),
+                ));
+            }
+            key.verify()?;
+            Ok(key)
+        })
+        .await
+    }
+
+    /// Store an encrypted secret
+    pub async fn put(&self, secret: &EncSecret) -> Result<(), Error> {
+        self.session(|client| async move {
+            let mut req = client.put_request();
+            write_secret(req.get().init_secret(), secret);
+            req.send().promise.await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Get the encrypted secret with the given header
+    pub async fn get(&self, header: &Header) -> Result<EncSecret, Error> {
+        self.session(|client| async move {
+            let mut req = client.get_request();
+            write_header(req.get().init_header(), header);
+            let res = req.send().promise.await?;
+            read_secret(res.get()?.get_secret()?)
+        })
+        .await
+    }
+
+    /// Get the encrypted secrets with the given labels, all in one session.
+    /// As when getting by name, the first secret with a label is taken.
+    /// Fails naming every label that no secret has.
+    pub async fn get_labeled(
+        &self,
+        labels: &[&str],
+    ) -> Result<Vec<EncSecret>, Error> {
+        self.session(|client| async move {
+            let res = client.list_request().send().promise.await?;
+            let headers = res
+                .get()?
+                .get_keys()?
+                .iter()
+                .map(read_header)
+                .collect::<Result<Vec<Header>, Error>>()?;
+
+            let mut found = Vec::with_capacity(labels.len());
+            let mut missing = vec![];
+            for label in labels {
+                match headers.iter().find(|h| h.label == *label) {
+                    Some(header) => found.push(header),
+                    None => missing.push(format!("\"{label}\"")),
+                }
+            }
+            if !missing.is_empty() {
+                return Err(Error::Fail(format!(
+                    "no such secret {}",
+                    missing.join(", ")
+                )));
+            }
+
+            let mut secrets = Vec::with_capacity(found.len());
+            for header in found {
+                let mut req = client.get_request();
+                write_header(req.get().init_header(), header);
+                let res = req.send().promise.await?;
+                secrets.push(read_secret(res.get()?.get_secret()?)?);
+            }
+            Ok(secrets)
+        })
+        .await
+    }
+
+    /// Store a file: its encrypted manifest, then the chunks of its content
+    /// one at a time as `chunks` yields them. Nothing is stored if any chunk
+    /// fails. Content already stored under the header is only put over if
+    /// `replace` is set.
+    pub async fn put_file<I>(
+        &self,
+        secret: &EncSecret,
+        chunks: I,
+        replace: bool,
+    ) -> Result<(), Error>
+    where
+        I: Iterator<Item = Result<Vec<u8>, Error>>,
+    {
+        self.session(|client| async move {
+            let mut req = client.put_file_request();
+            write_secret(req.get().init_secret(), secret);
+            req.get().set_replace(replace);
+            let sink = req.send().promise.await?.get()?.get_sink()?;
+            for chunk in chunks {
+                let mut req = sink.write_request();
+                req.get().set_chunk(&chunk?);
+                req.send().promise.await?;
+            }
+            sink.done_request().send().promise.await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Get the `chunks` chunks of a file's content, handing each to `f` in
+    /// order as it arrives. The manifest is got with `get`.
+    pub async fn get_file<F>(
+        &self,
+        header: &Header,
+        chunks: u64,
+        mut f: F,
+    ) -> Result<(), Error>
+    where
+        F: FnMut(&[u8]) -> Result<(), Error>,
+    {
+        self.session(|client| async move {
+            let mut req = client.get_file_request();
+            write_header(req.get().init_header(), header);
+            let source = req.send().promise.await?.get()?.get_source()?;
+            for _ in 0..chunks {
+                let res = source.read_request().send().promise.await?;
+                f(res.get()?.get_chunk()?)?;
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    /// Replace the master key with `new`, re-encrypting every secret under
+    /// it. `old` must be the current master key. Secrets under other keys
+    /// are left alone. Returns the number of secrets rotated. Requires the
+    /// admin role.
+    pub async fn rotate(
+        &self,
+        old: &dyn Encryptor,
+        new: ServerKey,
+        passphrase: &dyn PassphraseProvider,
+    ) -> Result<usize, Error> {
+        self.session(|client| async move {
+            let res = client.list_request().send().promise.await?;
+            let headers = res
+                .get()?
+                .get_keys()?
+                .iter()
+                .map(read_header)
+                .collect::<Result<Vec<Header>, Error>>()?;
+
+            let fingerprint = old.fingerprint()?;
+            let mut secrets = Vec::with_capacity(headers.len());
+            for header in &headers {
+                let mut req = client.get_request();
+                write_header(req.get().init_header(), header);
+                let res = req.send().promise.await?;
+                let secret = read_secret(res.get()?.get_secret()?)?;
+                if secret.key.is_some_and(|key| key != fingerprint) {
+                    continue;
+                }
+                secrets.push(new.encrypt(old.decrypt(secret)?)?);
+            }
+            let key = new.lock(passphrase)?;
+
+            let mut req = client.rotate_request();
+            write_master_key(req.get().init_key(), &key)?;
+            let mut list = req.get().init_secrets(secrets.len() as u32);
+            for (i, secret) in secrets.iter().enumerate() {
+                write_secret(list.reborrow().get(i as u32), secret);
+            }
+            req.send().promise.await?;
+            Ok(secrets.len())
+        })
+        .await
+    }
+
+    /// Get the master key replaced by an unconfirmed rotation. Requires the
+    /// admin role.
+    pub async fn previous_master_key(&self) -> Result<EncServerKey, Error> {
+        self.session(|client| async move {
+            let res =
+                client.get_previous_master_request().send().promise.await?;
+            read_master_key(res.get()?.get_key()?)
+        })
+        .await
+    }
+
+    /// Forget the key and ciphertexts replaced by the last rotation.
+    /// Requires the admin role.
+    pub async fn confirm_rotation(&self) -> Result<(), Error> {
+        self.session(|client| async move {
+            client.confirm_rotation_request().send().promise.await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Undo the last rotation. Requires the admin role.
+    pub async fn revert_rotation(&self) -> Result<(), Error> {
+        self.session(|client| async move {
+            client.revert_rotation_request().send().promise.await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Check every entry in the server's store for corruption. Requires the
+    /// admin role.
+    pub async fn verify(&self) -> Result<Report, Error> {
+        self.session(|client| async move {
+            let res = client.verify_request().send().promise.await?;
+            read_report(res.get()?.get_report()?)
+        })
+        .await
+    }
+
+    /// Create a user, or reset their password. Requires the admin role.
+    pub async fn add_user(
+        &self,
+        user: &str,
+        password: &str,
+    ) -> Result<(), Error> {
+        self.session(|client| async move {
+            let mut req = client.add_user_request();
+            req.get().set_user(user);
+            req.get().set_password(password);
+            req.send().promise.await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Assign a role to a user. Requires the admin role.
+    pub async fn grant(&self, grant: &Grant) -> Result<(), Error> {
+        self.session(|client| async move {
+            let mut req = client.grant_request();
+            write_grant(req.get().init_grant(), grant);
+            req.send().promise.await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Remove a role from a user. Requires the admin role.
+    pub async fn revoke(&self, grant: &Grant) -> Result<(), Error> {
+        self.session(|client| async move {
+            let mut req = client.revoke_request();
+            write_grant(req.get().init_grant(), grant);
+            req.send().promise.await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Get every grant on the server. Requires the admin role.
+    pub async fn grants(&self) -> Result<Vec<Grant>, Error> {
+        self.session(|client| async move {
+            let res = client.list_grants_request().send().promise.await?;
+            res.get()?.get_grants()?.iter().map(read_grant).collect()
+        })
+        .await
+    }
+
+    /// Mint an API token restricted to `ops` on labels matching `pattern`,
+    /// valid for `ttl` seconds. The token is only ever returned here.
+    pub async fn mint_token(
+        &self,
+        pattern: &str,
+        ops: &[Op],
+        ttl: u64,
+    ) -> Result<String, Error> {
+        self.session(|client| async move {
+            let mut req = client.mint_token_request();
+            req.get().set_pattern(pattern);
+            req.get().set_ttl(ttl);
+            let mut list = req.get().init_ops(ops.len() as u32);
+            for (i, op) in ops.iter().enumerate() {
+                list.set(i as u32, op_to_wire(*op)?);
+            }
+            let res = req.send().promise.await?;
+            Ok(res.get()?.get_token()?.to_string())
+        })
+        .await
+    }
+
+    /// Revoke an API token by its id. Requires being its issuer or an admin.
+    pub async fn revoke_token(&self, id: &str) -> Result<(), Error> {
+        self.session(|client| async move {
+            let mut req = client.revoke_token_request();
+            req.get().set_id(id);
+            req.send().promise.await?;
+            Ok(())
+        })
+        .await
+    }
 }
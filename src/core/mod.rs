@@ -1,3 +1,8 @@
+pub mod access;
+pub mod agent;
 pub mod client;
 pub mod server;
+pub mod ssh_agent;
 pub mod store;
+pub mod template;
+pub mod wire;
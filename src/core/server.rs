@@ -1,15 +1,23 @@
-use super::store::Store;
-use crate::crypto::types::EncServerKey;
+use super::access::{self, Grant, Identity, Op, TokenClaims};
+use super::store::{Report, Store, Upload};
+use super::wire::{
+    op_from_wire, read_grant, read_header, read_master_key, read_secret,
+    write_grant, write_header, write_master_key, write_public_key,
+    write_report, write_secret,
+};
+use crate::crypto::types::{EncServerKey, Fingerprint, PublicServerKey};
 use crate::primitives::secret::*;
 use crate::Error;
+use std::cell::RefCell;
 use std::path::Path;
+use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
-use crate::protocol_capnp;
-use crate::protocol_capnp::keyserver;
+use crate::protocol_capnp::keyserver::{self, chunk_sink, chunk_source};
 use capnp::capability::Promise;
 use capnp_rpc::{pry, rpc_twoparty_capnp, twoparty, RpcSystem};
 use futures::{AsyncReadExt, TryFutureExt};
-use log::{debug, info};
+use log::{info, warn};
 use std::net::ToSocketAddrs;
 
 macro_rules! bry {
@@ -21,49 +29,163 @@ macro_rules! bry {
     };
 }
 
-macro_rules! conv_tag {
-    ($tag:expr) => {
-        if let Some(t) = $tag {
-            match t {
-                Tag::APIKey => keyserver::Tag::Apikey,
-                Tag::PublicKey => keyserver::Tag::Publickey,
-                Tag::PrivateKey => keyserver::Tag::Privatekey,
-                Tag::Keypair => keyserver::Tag::Keypair,
-                Tag::Credentials => keyserver::Tag::Credentials,
-                Tag::Other => keyserver::Tag::Other,
-            }
-        } else {
-            keyserver::Tag::Other
-        }
-    };
-}
-
 /// A running server instance
 pub struct Server {
     pub db: Store, // should not be pub
-    master_key: EncServerKey,
     port: u16,
 }
 
+/// A connection to the server, acting on behalf of a single caller. Every
+/// session shares the same underlying `Server`.
+struct Session {
+    server: Rc<RefCell<Server>>,
+    identity: Identity,
+}
+
 pub trait Protocol {
-    fn get_master(&self) -> EncServerKey;
-    fn list(&self) -> Result<Vec<Header>, Error>;
-    fn put(&mut self, secret: EncSecret) -> Result<(), Error>;
-    fn get(&self, header: Header) -> Result<Option<EncSecret>, Error>;
-    fn delete(&mut self, header: Header) -> EncSecret;
+    fn get_master(
+        &self,
+        caller: &Identity,
+        fingerprint: Option<Fingerprint>,
+    ) -> Result<EncServerKey, Error>;
+    fn get_public(
+        &self,
+        caller: &Identity,
+        fingerprint: Option<Fingerprint>,
+    ) -> Result<PublicServerKey, Error>;
+    fn list(&self, caller: &Identity) -> Result<Vec<Header>, Error>;
+    fn put(
+        &mut self,
+        caller: &Identity,
+        secret: EncSecret,
+    ) -> Result<(), Error>;
+    fn get(
+        &self,
+        caller: &Identity,
+        header: Header,
+    ) -> Result<Option<EncSecret>, Error>;
+    fn delete(
+        &mut self,
+        caller: &Identity,
+        header: Header,
+    ) -> Result<Option<EncSecret>, Error>;
+
+    fn put_chunk(
+        &mut self,
+        caller: &Identity,
+        header: &Header,
+        upload: &Upload,
+        index: u32,
+        chunk: &[u8],
+    ) -> Result<(), Error>;
+    fn put_file(
+        &mut self,
+        caller: &Identity,
+        secret: EncSecret,
+        upload: &Upload,
+        chunks: u32,
+        replace: bool,
+    ) -> Result<(), Error>;
+    fn get_chunk(
+        &self,
+        caller: &Identity,
+        header: &Header,
+        index: u32,
+    ) -> Result<Vec<u8>, Error>;
+
+    fn login(&self, user: &str, password: &str) -> Result<Identity, Error>;
+    fn add_user(
+        &mut self,
+        caller: &Identity,
+        user: &str,
+        password: &str,
+    ) -> Result<(), Error>;
+    fn grant(&mut self, caller: &Identity, grant: Grant) -> Result<(), Error>;
+    fn revoke(&mut self, caller: &Identity, grant: Grant) -> Result<(), Error>;
+    fn grants(&self, caller: &Identity) -> Result<Vec<Grant>, Error>;
+
+    fn mint_token(
+        &mut self,
+        caller: &Identity,
+        pattern: &str,
+        ops: Vec<Op>,
+        ttl: u64,
+    ) -> Result<String, Error>;
+    fn login_token(&self, token: &str) -> Result<Identity, Error>;
+    fn revoke_token(
+        &mut self,
+        caller: &Identity,
+        id: &str,
+    ) -> Result<(), Error>;
+
+    fn rotate(
+        &mut self,
+        caller: &Identity,
+        key: EncServerKey,
+        secrets: Vec<EncSecret>,
+    ) -> Result<(), Error>;
+    fn get_previous_master(
+        &self,
+        caller: &Identity,
+    ) -> Result<EncServerKey, Error>;
+    fn confirm_rotation(&mut self, caller: &Identity) -> Result<(), Error>;
+    fn revert_rotation(&mut self, caller: &Identity) -> Result<(), Error>;
+
+    fn verify(&self, caller: &Identity) -> Result<Report, Error>;
 }
 
 impl Server {
+    /// Open the store at `db_path`. The first of `key_paths` only becomes
+    /// the master key of a new store; after that the store keeps its own,
+    /// so that it can be rotated. Every other key is held alongside it, for
+    /// clients that ask for it by fingerprint.
     pub fn init<P: AsRef<Path>>(
         db_path: P,
-        key_path: P,
+        key_paths: &[P],
         port: u16,
     ) -> Result<Self, Error> {
-        Ok(Self {
-            db: Store::load(db_path)?,
-            master_key: EncServerKey::load(key_path)?,
-            port,
-        })
+        let mut db = Store::load(db_path)?;
+        let mut keys = key_paths
+            .iter()
+            .map(|p| Ok((p.as_ref(), EncServerKey::load(p)?)))
+            .collect::<Result<Vec<(&Path, EncServerKey)>, Error>>()?;
+        if db.master_key()?.is_none() {
+            if keys.is_empty() {
+                return Err(Error::Fail("no master key given".to_string()));
+            }
+            let (path, key) = keys.remove(0);
+            match db.is_empty() {
+                true => info!("initializing store with master key {path:?}"),
+                false => warn!("adopting {path:?} as the store's master key"),
+            }
+            db.init_master_key(&key)?;
+        }
+
+        let master = db.master_key()?;
+        for (path, key) in keys {
+            if Some(&key) == master.as_ref() {
+                continue;
+            }
+            match db.add_key(&key) {
+                Ok(fingerprint) => info!("holding key {fingerprint}"),
+                Err(e) => warn!("not holding {path:?}: {e}"),
+            }
+        }
+        Ok(Self { db, port })
+    }
+
+    /// Create an admin with full access to every secret. Used to bootstrap
+    /// a new server before anyone can log in.
+    pub fn add_admin(
+        &mut self,
+        user: &str,
+        password: &str,
+    ) -> Result<(), Error> {
+        self.add_user(&Identity::Local, user, password)?;
+        self.grant(
+            &Identity::Local,
+            Grant::new(user, access::Role::Admin, access::Target::All),
+        )
     }
 
     /// Start serving requests
@@ -72,18 +194,25 @@ impl Server {
             .to_socket_addrs()?
             .next()
             .expect("could not parse address");
+        let port = self.port;
+        let server = Rc::new(RefCell::new(self));
 
         tokio::task::LocalSet::new()
             .run_until(async move {
                 let listener = tokio::net::TcpListener::bind(&addr).await?;
-                info!("serving on {}", self.port);
-
-                let rpc: keyserver::Client = capnp_rpc::new_client(self);
+                info!("serving on {}", port);
 
                 loop {
                     let (stream, _) = listener.accept().await?;
                     info!("handling new conn {stream:?}");
 
+                    // Each connection starts out anonymous
+                    let rpc: keyserver::Client =
+                        capnp_rpc::new_client(Session {
+                            server: server.clone(),
+                            identity: Identity::Anonymous,
+                        });
+
                     let (reader, writer) =
                         tokio_util::compat::TokioAsyncReadCompatExt::compat(
                             stream,
@@ -97,10 +226,8 @@ impl Server {
                         Default::default(),
                     );
 
-                    let rpc_system = RpcSystem::new(
-                        Box::new(network),
-                        Some(rpc.clone().client),
-                    );
+                    let rpc_system =
+                        RpcSystem::new(Box::new(network), Some(rpc.client));
                     tokio::task::spawn_local(
                         rpc_system.map_err(|e| eprintln!("error: {e:?}")),
                     );
@@ -112,41 +239,474 @@ impl Server {
     pub fn print_db(&self) {
         self.db.dump()
     }
+
+    /// Refuse to replace a signed secret with an unsigned one, such as a
+    /// deposit, which an admin might then accept without noticing
+    fn check_replace(&self, secret: &EncSecret) -> Result<(), Error> {
+        if secret.signature.is_some() {
+            return Ok(());
+        }
+        match self.db.get_secret(secret.header.clone())? {
+            Some(old) if old.signature.is_some() => {
+                Err(Error::Denied(format!(
+                    "{} is signed and cannot be replaced by an unsigned secret",
+                    secret.header.label
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Refuse to put a file over the content already stored under its
+    /// header, unless the caller asked to replace it
+    fn check_file(&self, header: &Header, replace: bool) -> Result<(), Error> {
+        match !replace && self.db.has_file(header)? {
+            true => Err(Error::Fail(format!(
+                "{} already has content, and is only put over if replaced",
+                header.label
+            ))),
+            false => Ok(()),
+        }
+    }
+
+    /// Check that `caller` may perform `op`, on `header` if given
+    fn authorize(
+        &self,
+        caller: &Identity,
+        op: Op,
+        header: Option<&Header>,
+    ) -> Result<(), Error> {
+        let grants = match caller {
+            Identity::User(user) => self.db.list_grants(Some(user))?,
+            Identity::Token(claims) => {
+                self.db.list_grants(Some(&claims.issuer))?
+            }
+            _ => vec![],
+        };
+        access::authorize(caller, &grants, op, header)
+    }
 }
 
 impl Protocol for Server {
-    fn get_master(&self) -> EncServerKey {
-        self.master_key.clone()
+    fn get_master(
+        &self,
+        caller: &Identity,
+        fingerprint: Option<Fingerprint>,
+    ) -> Result<EncServerKey, Error> {
+        self.authorize(caller, Op::GetMaster, None)?;
+        match fingerprint {
+            Some(fingerprint) => self.db.key(&fingerprint)?.ok_or_else(|| {
+                Error::Fail(format!("no key with fingerprint {fingerprint}"))
+            }),
+            None => self.db.master_key()?.ok_or_else(|| {
+                Error::Fail("store has no master key".to_string())
+            }),
+        }
     }
 
-    fn list(&self) -> Result<Vec<Header>, Error> {
-        self.db.list_secrets()
+    /// Anyone who may put secrets may encrypt them, so the public key is
+    /// guarded by `Op::Put` rather than `Op::GetMaster`
+    fn get_public(
+        &self,
+        caller: &Identity,
+        fingerprint: Option<Fingerprint>,
+    ) -> Result<PublicServerKey, Error> {
+        self.authorize(caller, Op::Put, None)?;
+        self.db.public_key(fingerprint.as_ref())?.ok_or_else(|| {
+            Error::Fail(match fingerprint {
+                Some(fingerprint) => {
+                    format!("no public key with fingerprint {fingerprint}")
+                }
+                None => "the master key has no public key: it was locked \
+                         before public keys, or the store has no master key. \
+                         Rotate it to publish one."
+                    .to_string(),
+            })
+        })
+    }
+
+    fn list(&self, caller: &Identity) -> Result<Vec<Header>, Error> {
+        self.authorize(caller, Op::List, None)?;
+        Ok(self
+            .db
+            .list_secrets()?
+            .into_iter()
+            .filter(|h| self.authorize(caller, Op::List, Some(h)).is_ok())
+            .collect())
     }
 
-    fn put(&mut self, secret: EncSecret) -> Result<(), Error> {
+    fn put(
+        &mut self,
+        caller: &Identity,
+        secret: EncSecret,
+    ) -> Result<(), Error> {
+        self.authorize(caller, Op::Put, Some(&secret.header))?;
+        self.check_replace(&secret)?;
         self.db.put_secret(secret)
     }
 
-    fn get(&self, header: Header) -> Result<Option<EncSecret>, Error> {
+    fn get(
+        &self,
+        caller: &Identity,
+        header: Header,
+    ) -> Result<Option<EncSecret>, Error> {
+        self.authorize(caller, Op::Get, Some(&header))?;
         self.db.get_secret(header)
     }
 
-    fn delete(&mut self, header: Header) -> EncSecret {
-        unimplemented!()
+    fn delete(
+        &mut self,
+        caller: &Identity,
+        header: Header,
+    ) -> Result<Option<EncSecret>, Error> {
+        self.authorize(caller, Op::Delete, Some(&header))?;
+        self.db.delete_secret(header)
+    }
+
+    fn put_chunk(
+        &mut self,
+        caller: &Identity,
+        header: &Header,
+        upload: &Upload,
+        index: u32,
+        chunk: &[u8],
+    ) -> Result<(), Error> {
+        self.authorize(caller, Op::Put, Some(header))?;
+        self.db.put_chunk(upload, index, chunk)
+    }
+
+    /// Store the manifest of a file once all of its chunks are in
+    fn put_file(
+        &mut self,
+        caller: &Identity,
+        secret: EncSecret,
+        upload: &Upload,
+        chunks: u32,
+        replace: bool,
+    ) -> Result<(), Error> {
+        self.authorize(caller, Op::Put, Some(&secret.header))?;
+        self.check_replace(&secret)?;
+        self.check_file(&secret.header, replace)?;
+        let stored = self.db.chunk_count(upload)?;
+        if stored != chunks {
+            return Err(Error::Fail(format!(
+                "expected {chunks} chunks of {} but {stored} are stored",
+                secret.header.label
+            )));
+        }
+        self.db.put_file(secret, upload)
+    }
+
+    fn get_chunk(
+        &self,
+        caller: &Identity,
+        header: &Header,
+        index: u32,
+    ) -> Result<Vec<u8>, Error> {
+        self.authorize(caller, Op::Get, Some(header))?;
+        self.db.get_chunk(header, index)?.ok_or_else(|| {
+            Error::Fail(format!("{} has no chunk {index}", header.label))
+        })
+    }
+
+    fn login(&self, user: &str, password: &str) -> Result<Identity, Error> {
+        if self.db.verify_user(user, password)? {
+            Ok(Identity::User(user.to_string()))
+        } else {
+            Err(Error::Denied("invalid username or password".to_string()))
+        }
+    }
+
+    fn add_user(
+        &mut self,
+        caller: &Identity,
+        user: &str,
+        password: &str,
+    ) -> Result<(), Error> {
+        self.authorize(caller, Op::Admin, None)?;
+        self.db.put_user(user, password)
+    }
+
+    fn grant(&mut self, caller: &Identity, grant: Grant) -> Result<(), Error> {
+        self.authorize(caller, Op::Admin, None)?;
+        self.db.put_grant(grant)
+    }
+
+    fn revoke(&mut self, caller: &Identity, grant: Grant) -> Result<(), Error> {
+        self.authorize(caller, Op::Admin, None)?;
+        match self.db.delete_grant(&grant.user, &grant.target)? {
+            true => Ok(()),
+            false => Err(Error::Fail(format!("no such grant {grant:?}"))),
+        }
+    }
+
+    fn grants(&self, caller: &Identity) -> Result<Vec<Grant>, Error> {
+        self.authorize(caller, Op::Admin, None)?;
+        self.db.list_grants(None)
+    }
+
+    fn mint_token(
+        &mut self,
+        caller: &Identity,
+        pattern: &str,
+        ops: Vec<Op>,
+        ttl: u64,
+    ) -> Result<String, Error> {
+        // Only users mint tokens, and only for operations they could perform
+        // themselves
+        let issuer = match caller {
+            Identity::User(user) => user.clone(),
+            _ => {
+                return Err(Error::Denied(
+                    "only users may mint tokens".to_string(),
+                ))
+            }
+        };
+        for op in &ops {
+            self.authorize(caller, *op, None)?;
+        }
+
+        let id = hex::encode(rand::random::<[u8; 8]>());
+        let secret = hex::encode(rand::random::<[u8; 32]>());
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let claims = TokenClaims {
+            id: id.clone(),
+            issuer,
+            pattern: pattern.to_string(),
+            ops,
+            expiration: now.saturating_add(ttl),
+        };
+        self.db.put_token(&claims, &secret)?;
+
+        Ok(format!("spark_{id}_{secret}"))
+    }
+
+    fn login_token(&self, token: &str) -> Result<Identity, Error> {
+        let invalid = || Error::Denied("invalid token".to_string());
+        let (id, secret) = token
+            .strip_prefix("spark_")
+            .and_then(|t| t.split_once('_'))
+            .ok_or_else(invalid)?;
+        let claims = self.db.verify_token(id, secret)?.ok_or_else(invalid)?;
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        if now >= claims.expiration {
+            return Err(Error::Denied(format!("token {id} expired")));
+        }
+        Ok(Identity::Token(claims))
+    }
+
+    fn revoke_token(
+        &mut self,
+        caller: &Identity,
+        id: &str,
+    ) -> Result<(), Error> {
+        let (_, claims) = self
+            .db
+            .get_token(id)?
+            .ok_or_else(|| Error::Fail(format!("no such token {id}")))?;
+        if caller != &Identity::User(claims.issuer) {
+            self.authorize(caller, Op::Admin, None)?;
+        }
+        self.db.delete_token(id)?;
+        Ok(())
+    }
+
+    fn rotate(
+        &mut self,
+        caller: &Identity,
+        key: EncServerKey,
+        secrets: Vec<EncSecret>,
+    ) -> Result<(), Error> {
+        self.authorize(caller, Op::Admin, None)?;
+        info!("rotating master key of {} secrets", secrets.len());
+        self.db.rotate(&key, secrets)
+    }
+
+    fn get_previous_master(
+        &self,
+        caller: &Identity,
+    ) -> Result<EncServerKey, Error> {
+        self.authorize(caller, Op::Admin, None)?;
+        self.db
+            .previous_master_key()?
+            .ok_or_else(|| Error::Fail("no rotation is pending".to_string()))
+    }
+
+    fn confirm_rotation(&mut self, caller: &Identity) -> Result<(), Error> {
+        self.authorize(caller, Op::Admin, None)?;
+        self.db.confirm_rotation()
+    }
+
+    fn revert_rotation(&mut self, caller: &Identity) -> Result<(), Error> {
+        self.authorize(caller, Op::Admin, None)?;
+        self.db.revert_rotation()
+    }
+
+    fn verify(&self, caller: &Identity) -> Result<Report, Error> {
+        self.authorize(caller, Op::Admin, None)?;
+        let report = self.db.verify()?;
+        info!(
+            "verified store: {} ok, {} unchecked, {} problems",
+            report.ok,
+            report.unchecked,
+            report.problems.len()
+        );
+        Ok(report)
+    }
+}
+
+/// Receives the chunks of a file being put, as an upload of their own. The
+/// manifest is only stored once every chunk is in, and the chunks are
+/// dropped if it never is.
+struct ChunkSink {
+    server: Rc<RefCell<Server>>,
+    identity: Identity,
+    secret: Option<EncSecret>,
+    upload: Upload,
+    replace: bool,
+    written: u32,
+}
+
+/// Sends the chunks of a file, in order
+struct ChunkSource {
+    server: Rc<RefCell<Server>>,
+    identity: Identity,
+    header: Header,
+    read: u32,
+}
+
+impl chunk_sink::Server for ChunkSink {
+    fn write(
+        &mut self,
+        params: chunk_sink::WriteParams,
+        _: chunk_sink::WriteResults,
+    ) -> Promise<(), capnp::Error> {
+        let header = match &self.secret {
+            Some(secret) => &secret.header,
+            None => {
+                return Promise::err(capnp::Error::failed(
+                    "file is already done".to_string(),
+                ))
+            }
+        };
+        let chunk = pry!(pry!(params.get()).get_chunk());
+        bry!(self.server.borrow_mut().put_chunk(
+            &self.identity,
+            header,
+            &self.upload,
+            self.written,
+            chunk
+        ));
+        self.written += 1;
+
+        Promise::ok(())
+    }
+
+    fn done(
+        &mut self,
+        _: chunk_sink::DoneParams,
+        _: chunk_sink::DoneResults,
+    ) -> Promise<(), capnp::Error> {
+        let secret = match self.secret.take() {
+            Some(secret) => secret,
+            None => {
+                return Promise::err(capnp::Error::failed(
+                    "file is already done".to_string(),
+                ))
+            }
+        };
+        let header = secret.header.clone();
+        let mut server = self.server.borrow_mut();
+        if let Err(e) = server.put_file(
+            &self.identity,
+            secret,
+            &self.upload,
+            self.written,
+            self.replace,
+        ) {
+            if let Err(e) = server.db.discard_chunks(&self.upload) {
+                warn!("discarding chunks of {}: {e}", header.label);
+            }
+            return Promise::err(capnp::Error::failed(e.to_string()));
+        }
+        info!("stored {} in {} chunks", header.label, self.written);
+
+        Promise::ok(())
+    }
+}
+
+impl Drop for ChunkSink {
+    fn drop(&mut self) {
+        let Some(secret) = &self.secret else { return };
+        if self.written == 0 {
+            return;
+        }
+        warn!("upload of {} abandoned", secret.header.label);
+        match self.server.try_borrow_mut() {
+            Ok(mut server) => {
+                if let Err(e) = server.db.discard_chunks(&self.upload) {
+                    warn!("discarding chunks of {}: {e}", secret.header.label);
+                }
+            }
+            Err(_) => warn!("could not discard {}", secret.header.label),
+        }
+    }
+}
+
+impl chunk_source::Server for ChunkSource {
+    fn read(
+        &mut self,
+        _: chunk_source::ReadParams,
+        mut results: chunk_source::ReadResults,
+    ) -> Promise<(), capnp::Error> {
+        let chunk = bry!(self.server.borrow().get_chunk(
+            &self.identity,
+            &self.header,
+            self.read
+        ));
+        results.get().set_chunk(&chunk);
+        self.read += 1;
+
+        Promise::ok(())
     }
 }
 
-impl keyserver::Server for Server {
+impl keyserver::Server for Session {
     fn get_master(
         &mut self,
-        _: keyserver::GetMasterParams,
+        params: keyserver::GetMasterParams,
         mut results: keyserver::GetMasterResults,
     ) -> Promise<(), capnp::Error> {
-        let master = <Server as Protocol>::get_master(self);
-        let mut res = results.get().init_key();
-        res.set_salt(&master.salt);
-        res.set_nonce(&master.nonce);
-        res.set_rawkey(&master.server_key[..]);
+        let params = pry!(params.get());
+        let fingerprint = match params.has_fingerprint() {
+            true => Some(bry!(Fingerprint::from_slice(pry!(
+                params.get_fingerprint()
+            )))),
+            false => None,
+        };
+        let master =
+            bry!(self.server.borrow().get_master(&self.identity, fingerprint));
+        bry!(write_master_key(results.get().init_key(), &master));
+
+        Promise::ok(())
+    }
+
+    fn get_public(
+        &mut self,
+        params: keyserver::GetPublicParams,
+        mut results: keyserver::GetPublicResults,
+    ) -> Promise<(), capnp::Error> {
+        let params = pry!(params.get());
+        let fingerprint = match params.has_fingerprint() {
+            true => Some(bry!(Fingerprint::from_slice(pry!(
+                params.get_fingerprint()
+            )))),
+            false => None,
+        };
+        let public =
+            bry!(self.server.borrow().get_public(&self.identity, fingerprint));
+        bry!(write_public_key(results.get().init_key(), &public));
 
         Promise::ok(())
     }
@@ -156,15 +716,11 @@ impl keyserver::Server for Server {
         _: keyserver::ListParams,
         mut results: keyserver::ListResults,
     ) -> Promise<(), capnp::Error> {
-        let secrets = bry!(<Server as Protocol>::list(self));
+        let secrets = bry!(self.server.borrow().list(&self.identity));
 
         let mut res = results.get().init_keys(secrets.len() as u32);
-        secrets.into_iter().enumerate().for_each(|(i, sec)| {
-            res.reborrow().get(i as u32).set_id(&sec.id.bytes()[..]);
-            res.reborrow().get(i as u32).set_tag(conv_tag!(sec.tag));
-            res.reborrow()
-                .get(i as u32)
-                .set_desc(&sec.desc.unwrap_or_default());
+        secrets.iter().enumerate().for_each(|(i, sec)| {
+            write_header(res.reborrow().get(i as u32), sec);
         });
 
         Promise::ok(())
@@ -173,8 +729,11 @@ impl keyserver::Server for Server {
     fn put(
         &mut self,
         params: keyserver::PutParams,
-        mut results: keyserver::PutResults,
+        _: keyserver::PutResults,
     ) -> Promise<(), capnp::Error> {
+        let secret = bry!(read_secret(pry!(pry!(params.get()).get_secret())));
+        bry!(self.server.borrow_mut().put(&self.identity, secret));
+
         Promise::ok(())
     }
 
@@ -183,6 +742,19 @@ impl keyserver::Server for Server {
         params: keyserver::GetParams,
         mut results: keyserver::GetResults,
     ) -> Promise<(), capnp::Error> {
+        let header = bry!(read_header(pry!(pry!(params.get()).get_header())));
+        let secret =
+            match bry!(self.server.borrow().get(&self.identity, header)) {
+                Some(s) => s,
+                None => {
+                    return Promise::err(capnp::Error::failed(
+                        "no such secret".to_string(),
+                    ))
+                }
+            };
+
+        write_secret(results.get().init_secret(), &secret);
+
         Promise::ok(())
     }
 
@@ -191,6 +763,374 @@ impl keyserver::Server for Server {
         params: keyserver::DeleteParams,
         mut results: keyserver::DeleteResults,
     ) -> Promise<(), capnp::Error> {
+        let header = bry!(read_header(pry!(pry!(params.get()).get_header())));
+        let secret =
+            match bry!(self.server.borrow_mut().delete(&self.identity, header))
+            {
+                Some(s) => s,
+                None => {
+                    return Promise::err(capnp::Error::failed(
+                        "no such secret".to_string(),
+                    ))
+                }
+            };
+
+        write_secret(results.get().init_secret(), &secret);
+
+        Promise::ok(())
+    }
+
+    fn login(
+        &mut self,
+        params: keyserver::LoginParams,
+        mut results: keyserver::LoginResults,
+    ) -> Promise<(), capnp::Error> {
+        let params = pry!(params.get());
+        let identity = bry!(self
+            .server
+            .borrow()
+            .login(pry!(params.get_user()), pry!(params.get_password())));
+        info!("logged in as {identity:?}");
+
+        results.get().set_session(capnp_rpc::new_client(Session {
+            server: self.server.clone(),
+            identity,
+        }));
+
+        Promise::ok(())
+    }
+
+    fn add_user(
+        &mut self,
+        params: keyserver::AddUserParams,
+        _: keyserver::AddUserResults,
+    ) -> Promise<(), capnp::Error> {
+        let params = pry!(params.get());
+        bry!(self.server.borrow_mut().add_user(
+            &self.identity,
+            pry!(params.get_user()),
+            pry!(params.get_password()),
+        ));
+
+        Promise::ok(())
+    }
+
+    fn grant(
+        &mut self,
+        params: keyserver::GrantParams,
+        _: keyserver::GrantResults,
+    ) -> Promise<(), capnp::Error> {
+        let grant = bry!(read_grant(pry!(pry!(params.get()).get_grant())));
+        bry!(self.server.borrow_mut().grant(&self.identity, grant));
+
+        Promise::ok(())
+    }
+
+    fn revoke(
+        &mut self,
+        params: keyserver::RevokeParams,
+        _: keyserver::RevokeResults,
+    ) -> Promise<(), capnp::Error> {
+        let grant = bry!(read_grant(pry!(pry!(params.get()).get_grant())));
+        bry!(self.server.borrow_mut().revoke(&self.identity, grant));
+
+        Promise::ok(())
+    }
+
+    fn list_grants(
+        &mut self,
+        _: keyserver::ListGrantsParams,
+        mut results: keyserver::ListGrantsResults,
+    ) -> Promise<(), capnp::Error> {
+        let grants = bry!(self.server.borrow().grants(&self.identity));
+
+        let mut res = results.get().init_grants(grants.len() as u32);
+        grants.iter().enumerate().for_each(|(i, grant)| {
+            write_grant(res.reborrow().get(i as u32), grant);
+        });
+
+        Promise::ok(())
+    }
+
+    fn mint_token(
+        &mut self,
+        params: keyserver::MintTokenParams,
+        mut results: keyserver::MintTokenResults,
+    ) -> Promise<(), capnp::Error> {
+        let params = pry!(params.get());
+        let ops = pry!(pry!(params.get_ops())
+            .iter()
+            .map(|op| op.map(op_from_wire))
+            .collect::<Result<Vec<Op>, capnp::NotInSchema>>());
+        let token = bry!(self.server.borrow_mut().mint_token(
+            &self.identity,
+            pry!(params.get_pattern()),
+            ops,
+            params.get_ttl(),
+        ));
+        results.get().set_token(&token);
+
+        Promise::ok(())
+    }
+
+    fn login_token(
+        &mut self,
+        params: keyserver::LoginTokenParams,
+        mut results: keyserver::LoginTokenResults,
+    ) -> Promise<(), capnp::Error> {
+        let token = pry!(pry!(params.get()).get_token());
+        let identity = bry!(self.server.borrow().login_token(token));
+        info!("logged in as {identity:?}");
+
+        results.get().set_session(capnp_rpc::new_client(Session {
+            server: self.server.clone(),
+            identity,
+        }));
+
         Promise::ok(())
     }
+
+    fn revoke_token(
+        &mut self,
+        params: keyserver::RevokeTokenParams,
+        _: keyserver::RevokeTokenResults,
+    ) -> Promise<(), capnp::Error> {
+        let id = pry!(pry!(params.get()).get_id());
+        bry!(self.server.borrow_mut().revoke_token(&self.identity, id));
+
+        Promise::ok(())
+    }
+
+    fn rotate(
+        &mut self,
+        params: keyserver::RotateParams,
+        _: keyserver::RotateResults,
+    ) -> Promise<(), capnp::Error> {
+        let params = pry!(params.get());
+        let key = bry!(read_master_key(pry!(params.get_key())));
+        let secrets = bry!(pry!(params.get_secrets())
+            .iter()
+            .map(read_secret)
+            .collect::<Result<Vec<EncSecret>, Error>>());
+        bry!(self
+            .server
+            .borrow_mut()
+            .rotate(&self.identity, key, secrets));
+
+        Promise::ok(())
+    }
+
+    fn get_previous_master(
+        &mut self,
+        _: keyserver::GetPreviousMasterParams,
+        mut results: keyserver::GetPreviousMasterResults,
+    ) -> Promise<(), capnp::Error> {
+        let previous =
+            bry!(self.server.borrow().get_previous_master(&self.identity));
+        bry!(write_master_key(results.get().init_key(), &previous));
+
+        Promise::ok(())
+    }
+
+    fn confirm_rotation(
+        &mut self,
+        _: keyserver::ConfirmRotationParams,
+        _: keyserver::ConfirmRotationResults,
+    ) -> Promise<(), capnp::Error> {
+        bry!(self.server.borrow_mut().confirm_rotation(&self.identity));
+
+        Promise::ok(())
+    }
+
+    fn revert_rotation(
+        &mut self,
+        _: keyserver::RevertRotationParams,
+        _: keyserver::RevertRotationResults,
+    ) -> Promise<(), capnp::Error> {
+        bry!(self.server.borrow_mut().revert_rotation(&self.identity));
+
+        Promise::ok(())
+    }
+
+    fn verify(
+        &mut self,
+        _: keyserver::VerifyParams,
+        mut results: keyserver::VerifyResults,
+    ) -> Promise<(), capnp::Error> {
+        let report = bry!(self.server.borrow().verify(&self.identity));
+        write_report(results.get().init_report(), &report);
+
+        Promise::ok(())
+    }
+
+    fn put_file(
+        &mut self,
+        params: keyserver::PutFileParams,
+        mut results: keyserver::PutFileResults,
+    ) -> Promise<(), capnp::Error> {
+        let params = pry!(params.get());
+        let secret = bry!(read_secret(pry!(params.get_secret())));
+        let replace = params.get_replace();
+        // Check before any chunks are sent, not only once they are
+        let server = self.server.borrow();
+        bry!(server.authorize(&self.identity, Op::Put, Some(&secret.header)));
+        bry!(server.check_file(&secret.header, replace));
+
+        results.get().set_sink(capnp_rpc::new_client(ChunkSink {
+            server: self.server.clone(),
+            identity: self.identity.clone(),
+            secret: Some(secret),
+            upload: server.db.new_upload(),
+            replace,
+            written: 0,
+        }));
+
+        Promise::ok(())
+    }
+
+    fn get_file(
+        &mut self,
+        params: keyserver::GetFileParams,
+        mut results: keyserver::GetFileResults,
+    ) -> Promise<(), capnp::Error> {
+        let header = bry!(read_header(pry!(pry!(params.get()).get_header())));
+        // Only the chunks of a stored secret, under its own header
+        if bry!(self.server.borrow().get(&self.identity, header.clone()))
+            .is_none()
+        {
+            return Promise::err(capnp::Error::failed(
+                "no such secret".to_string(),
+            ));
+        }
+
+        results.get().set_source(capnp_rpc::new_client(ChunkSource {
+            server: self.server.clone(),
+            identity: self.identity.clone(),
+            header,
+            read: 0,
+        }));
+
+        Promise::ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::suite::CipherSuite;
+    use std::fs;
+
+    #[test]
+    fn test_baseline_store() {
+        // A store written before the master key was kept in it
+        let dir = std::env::temp_dir().join("spark_test_baseline");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir(&dir).unwrap();
+        for entry in fs::read_dir("./data/db1").unwrap() {
+            let path = entry.unwrap().path();
+            fs::copy(&path, dir.join(path.file_name().unwrap())).unwrap();
+        }
+        let key_path = Path::new("./data/336d78316b4c.esk");
+        let key = EncServerKey::load(key_path).unwrap();
+
+        for _ in 0..2 {
+            let server = Server::init(dir.as_path(), &[key_path], 0).unwrap();
+            assert_eq!(server.db.master_key().unwrap(), Some(key.clone()));
+            let headers = server.list(&Identity::Local).unwrap();
+            let header =
+                headers.iter().find(|h| h.label == "first secret").unwrap();
+            let secret = server.get(&Identity::Local, header.clone()).unwrap();
+            assert!(secret.is_some());
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_replace_signed() {
+        let dir = std::env::temp_dir().join("spark_test_replace");
+        let _ = fs::remove_dir_all(&dir);
+        let key_path = Path::new("./data/336d78316b4c.esk");
+        let mut server = Server::init(dir.as_path(), &[key_path], 0).unwrap();
+
+        let header =
+            Header::new("signed", None, None, 0, Scope::Public).unwrap();
+        let secret = |signature| EncSecret {
+            secret: vec![1, 2, 3],
+            header: header.clone(),
+            suite: CipherSuite::X25519,
+            signature,
+            key: None,
+        };
+        let signature = Some(ed25519_dalek::Signature::from_bytes(&[7; 64]));
+        server.put(&Identity::Local, secret(None)).unwrap();
+        server.put(&Identity::Local, secret(signature)).unwrap();
+        // A deposit may not take the place of a signed secret
+        assert!(matches!(
+            server.put(&Identity::Local, secret(None)),
+            Err(Error::Denied(_))
+        ));
+        server.put(&Identity::Local, secret(signature)).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_interrupted_upload() {
+        let dir = std::env::temp_dir().join("spark_test_upload");
+        let _ = fs::remove_dir_all(&dir);
+        let key_path = Path::new("./data/336d78316b4c.esk");
+        let server = Server::init(dir.as_path(), &[key_path], 0).unwrap();
+        let server = Rc::new(RefCell::new(server));
+        let local = &Identity::Local;
+
+        let header = Header::new("file", None, None, 0, Scope::Public).unwrap();
+        let secret = || EncSecret {
+            secret: vec![1, 2, 3],
+            header: header.clone(),
+            suite: CipherSuite::X25519,
+            signature: None,
+            key: None,
+        };
+        let upload = |chunks: &[&[u8]]| {
+            let mut server = server.borrow_mut();
+            let upload = server.db.new_upload();
+            for (i, chunk) in chunks.iter().enumerate() {
+                server
+                    .put_chunk(local, &header, &upload, i as u32, chunk)
+                    .unwrap();
+            }
+            upload
+        };
+        let read = |index| server.borrow().get_chunk(local, &header, index);
+
+        let first = upload(&[b"old 0", b"old 1"]);
+        server
+            .borrow_mut()
+            .put_file(local, secret(), &first, 2, false)
+            .unwrap();
+
+        // The connection drops halfway through putting it again
+        let sink = ChunkSink {
+            server: server.clone(),
+            identity: Identity::Local,
+            secret: Some(secret()),
+            upload: upload(&[b"new 0"]),
+            replace: true,
+            written: 1,
+        };
+        drop(sink);
+        assert_eq!(read(0).unwrap(), b"old 0");
+        assert_eq!(read(1).unwrap(), b"old 1");
+
+        // Only put over on purpose
+        let again = upload(&[b"new 0"]);
+        let mut db = server.borrow_mut();
+        assert!(db.put_file(local, secret(), &again, 1, false).is_err());
+        assert_eq!(db.get_chunk(local, &header, 0).unwrap(), b"old 0");
+        db.put_file(local, secret(), &again, 1, true).unwrap();
+        assert_eq!(db.get_chunk(local, &header, 0).unwrap(), b"new 0");
+        assert!(db.get_chunk(local, &header, 1).is_err());
+        assert!(db.verify(local).unwrap().problems.is_empty());
+        drop(db);
+        fs::remove_dir_all(&dir).unwrap();
+    }
 }
@@ -0,0 +1,229 @@
+//! An ssh-agent that signs with the SSH keys kept in the vault. It speaks
+//! the OpenSSH agent protocol (draft-miller-ssh-agent) on a Unix socket, so
+//! `ssh`, `ssh-add -L` and `ssh-keygen -Y sign` can use it through
+//! `SSH_AUTH_SOCK`.
+//!
+//! Keys are the `Keypair` secrets tagged `SshKey`. They are fetched and
+//! decrypted for each request that needs them and dropped once it is
+//! answered, so private keys are neither written out nor held in between.
+//! Adding and removing keys through the agent is refused; store them with
+//! the client instead.
+
+use super::agent;
+use super::client::Client;
+use crate::crypto::ssh::{self, Reader, Writer};
+use crate::crypto::Encryptor;
+use crate::primitives::payloads::{KeypairPayload, Payload};
+use crate::primitives::secret::Tag;
+use crate::Error;
+use log::warn;
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::UnixListener;
+use zeroize::Zeroizing;
+
+/// The environment variable SSH looks in for an agent's socket
+pub const SOCKET_ENV: &str = "SSH_AUTH_SOCK";
+
+const FAILURE: u8 = 5;
+const REQUEST_IDENTITIES: u8 = 11;
+const IDENTITIES_ANSWER: u8 = 12;
+const SIGN_REQUEST: u8 = 13;
+const SIGN_RESPONSE: u8 = 14;
+
+/// Messages larger than this are refused rather than allocated
+const MAX_MESSAGE: u32 = 256 << 10;
+
+/// How long a connection may sit idle before it is dropped, since only one
+/// is served at a time
+const IDLE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// An SSH key from the vault
+pub struct SshKey {
+    /// The label of the secret, which SSH shows as the key's comment
+    pub comment: String,
+    pub pair: KeypairPayload,
+}
+
+/// The socket used when none is given
+pub fn default_socket() -> Result<PathBuf, Error> {
+    agent::runtime_socket("ssh-agent")
+}
+
+/// Whether answering `message` needs the keys
+pub fn needs_keys(message: &[u8]) -> bool {
+    matches!(message.first(), Some(&REQUEST_IDENTITIES | &SIGN_REQUEST))
+}
+
+/// Answer one message, without its length prefix. Anything that cannot be
+/// answered gets a failure, as the protocol asks.
+pub fn respond(message: &[u8], keys: &[SshKey]) -> Vec<u8> {
+    match try_respond(message, keys) {
+        Ok(Some(response)) => response,
+        Ok(None) => vec![FAILURE],
+        Err(e) => {
+            warn!("ssh agent request: {e}");
+            vec![FAILURE]
+        }
+    }
+}
+
+fn try_respond(
+    message: &[u8],
+    keys: &[SshKey],
+) -> Result<Option<Vec<u8>>, Error> {
+    let mut r = Reader(message);
+    match r.byte()? {
+        REQUEST_IDENTITIES => {
+            let mut w = Writer::default();
+            w.byte(IDENTITIES_ANSWER).u32(keys.len() as u32);
+            for key in keys {
+                w.string(&ssh::public_blob(&key.pair)?)
+                    .string(key.comment.as_bytes());
+            }
+            Ok(Some(w.finish()))
+        }
+        SIGN_REQUEST => {
+            let blob = r.string()?;
+            let data = r.string()?;
+            let flags = r.u32()?;
+            for key in keys {
+                if ssh::public_blob(&key.pair)? == blob {
+                    let signature = ssh::sign(&key.pair, data, flags)?;
+                    return Ok(Some(
+                        Writer::default()
+                            .byte(SIGN_RESPONSE)
+                            .string(&signature)
+                            .finish(),
+                    ));
+                }
+            }
+            Ok(None)
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Fetch and decrypt the SSH keys under `key`
+async fn load(
+    client: &Client,
+    key: &dyn Encryptor,
+) -> Result<Vec<SshKey>, Error> {
+    let fingerprint = key.fingerprint()?;
+    let mut keys = vec![];
+    for header in client.list().await? {
+        if header.tag != Some(Tag::SshKey) {
+            continue;
+        }
+        let enc = client.get(&header).await?;
+        if enc.key.is_some_and(|k| k != fingerprint) {
+            continue;
+        }
+        // One bad secret should not take every other key down with it
+        match key.decrypt(enc).map(|secret| secret.secret) {
+            Ok(Payload::Keypair(pair)) if ssh::is_ssh(pair.key_type()) => keys
+                .push(SshKey {
+                    comment: header.label,
+                    pair,
+                }),
+            Ok(_) => warn!("{} is tagged SshKey but is not one", header.label),
+            Err(e) => warn!("skipping {}: {e}", header.label),
+        }
+    }
+    Ok(keys)
+}
+
+/// Serve SSH on a socket from `agent::bind`, one connection at a time, until
+/// the process is killed
+pub async fn serve(
+    listener: UnixListener,
+    client: &Client,
+    key: &dyn Encryptor,
+) -> Result<(), Error> {
+    loop {
+        let (mut stream, _) = listener.accept().await?;
+        if let Err(e) = agent::check_peer(&stream) {
+            warn!("refusing connection: {e}");
+            continue;
+        }
+        loop {
+            let message = tokio::time::timeout(IDLE_TIMEOUT, async {
+                let len = stream.read_u32().await?;
+                if len > MAX_MESSAGE {
+                    return Err(Error::Fail(format!("message of {len} bytes")));
+                }
+                let mut buf = vec![0u8; len as usize];
+                stream.read_exact(&mut buf).await?;
+                Ok(buf)
+            })
+            .await;
+            // Closed, idle or garbled, the connection is done with
+            let Ok(Ok(message)) = message else {
+                break;
+            };
+            let keys = match needs_keys(&message) {
+                true => load(client, key).await.unwrap_or_else(|e| {
+                    warn!("loading SSH keys: {e}");
+                    vec![]
+                }),
+                false => vec![],
+            };
+            let response = respond(&message, &keys);
+            drop(keys);
+            let mut out =
+                Zeroizing::new(Vec::with_capacity(4 + response.len()));
+            out.extend_from_slice(&(response.len() as u32).to_be_bytes());
+            out.extend_from_slice(&response);
+            if let Err(e) = stream.write_all(&out).await {
+                warn!("writing ssh agent response: {e}");
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::payloads::KeypairType;
+
+    #[test]
+    fn test_ssh_agent() {
+        let keys = vec![SshKey {
+            comment: "deploy".to_string(),
+            pair: KeypairPayload::generate(KeypairType::Ed25519).unwrap(),
+        }];
+        let blob = ssh::public_blob(&keys[0].pair).unwrap();
+
+        let answer = respond(&[REQUEST_IDENTITIES], &keys);
+        let mut r = Reader(&answer);
+        assert_eq!(r.byte().unwrap(), IDENTITIES_ANSWER);
+        assert_eq!(r.u32().unwrap(), 1);
+        assert_eq!(r.string().unwrap(), &blob[..]);
+        assert_eq!(r.string().unwrap(), b"deploy");
+
+        let request = |blob: &[u8]| {
+            Writer::default()
+                .byte(SIGN_REQUEST)
+                .string(blob)
+                .string(b"challenge")
+                .u32(0)
+                .finish()
+        };
+        let answer = respond(&request(&blob), &keys);
+        let mut r = Reader(&answer);
+        assert_eq!(r.byte().unwrap(), SIGN_RESPONSE);
+        assert_eq!(
+            r.string().unwrap(),
+            ssh::sign(&keys[0].pair, b"challenge", 0).unwrap()
+        );
+
+        // Unknown keys, truncated requests and other messages fail
+        assert_eq!(respond(&request(b"other"), &keys), [FAILURE]);
+        assert_eq!(respond(&[SIGN_REQUEST, 0, 0], &keys), [FAILURE]);
+        assert_eq!(respond(&[17], &keys), [FAILURE]);
+        assert_eq!(respond(&[], &keys), [FAILURE]);
+        assert!(!needs_keys(&[17]) && needs_keys(&[SIGN_REQUEST]));
+    }
+}
@@ -1,28 +1,216 @@
-use crate::crypto::types::EncServerKey;
+use super::access::{Grant, Role, Target, TokenClaims};
+use crate::crypto::suite::CipherSuite;
+use crate::crypto::types::{EncServerKey, Fingerprint, PublicServerKey};
 use crate::primitives::secret::{EncSecret, Header, SecretID};
 use crate::Error;
-
+use argon2::{
+    password_hash::{
+        rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier,
+        SaltString,
+    },
+    Argon2,
+};
+use log::warn;
+use sha2::{Digest, Sha256};
+use sled::transaction::ConflictableTransactionError;
+use sled::Transactional;
+use std::collections::BTreeSet;
 use std::path::Path;
 
+const CURRENT_KEY: &[u8] = b"current";
+const PREVIOUS_KEY: &[u8] = b"previous";
+
+/// Stored ciphertexts start with this and a format version. Values without
+/// it are raw PKCS#1 v1.5 ciphertexts from before cipher suites. Version 1
+/// entries predate signatures, version 2 checksums, and version 3 key
+/// fingerprints.
+const ENTRY_MAGIC: &[u8] = b"spark\0";
+const ENTRY_VERSION: u8 = 4;
+const SUM_LEN: usize = 32;
+
+/// A SHA-256 checksum over a secret's key (its header) and entry
+fn checksum(key: &[u8], body: &[u8]) -> [u8; SUM_LEN] {
+    Sha256::new()
+        .chain_update(key)
+        .chain_update(body)
+        .finalize()
+        .into()
+}
+
+/// Check the checksum of an entry. `None` for entries older than
+/// checksums.
+fn checksum_ok(key: &[u8], value: &[u8]) -> Option<bool> {
+    match value.strip_prefix(ENTRY_MAGIC) {
+        Some([3 | ENTRY_VERSION, rest @ ..]) => Some(
+            rest.len() >= SUM_LEN
+                && rest[..SUM_LEN] == checksum(key, &rest[SUM_LEN..]),
+        ),
+        _ => None,
+    }
+}
+
+/// Serialize the value stored for a secret under `key`
+fn encode_entry(key: &[u8], secret: &EncSecret) -> Result<Vec<u8>, Error> {
+    let body = bincode::serialize(&(
+        secret.suite,
+        &secret.secret,
+        &secret.signature,
+        &secret.key,
+    ))?;
+    let mut buf = [ENTRY_MAGIC, &[ENTRY_VERSION]].concat();
+    buf.extend(checksum(key, &body));
+    buf.extend(body);
+    Ok(buf)
+}
+
+/// Deserialize a value stored under `key` back into a secret
+fn decode_entry(
+    key: &[u8],
+    header: Header,
+    value: &[u8],
+) -> Result<EncSecret, Error> {
+    if checksum_ok(key, value) == Some(false) {
+        return Err(Error::Fail(format!("secret {} is corrupt", header.label)));
+    }
+    let (suite, secret, signature, key) = match value.strip_prefix(ENTRY_MAGIC)
+    {
+        Some([ENTRY_VERSION, rest @ ..]) => {
+            bincode::deserialize(&rest[SUM_LEN..])?
+        }
+        Some([3, rest @ ..]) => {
+            let (suite, secret, signature) =
+                bincode::deserialize(&rest[SUM_LEN..])?;
+            (suite, secret, signature, None)
+        }
+        Some([2, rest @ ..]) => {
+            let (suite, secret, signature) = bincode::deserialize(rest)?;
+            (suite, secret, signature, None)
+        }
+        Some([1, rest @ ..]) => {
+            let (suite, secret) = bincode::deserialize(rest)?;
+            (suite, secret, None, None)
+        }
+        Some(_) => {
+            return Err(Error::Fail("unknown secret entry version".into()))
+        }
+        None => (CipherSuite::RsaPkcs1v15, value.to_vec(), None, None),
+    };
+    Ok(EncSecret {
+        header,
+        secret,
+        suite,
+        signature,
+        key,
+    })
+}
+
+/// A file secret's content is found by a hash of the secret's key, so that
+/// it belongs to the whole header and not just its id
+fn file_key(key: &[u8]) -> [u8; SUM_LEN] {
+    Sha256::digest(key).into()
+}
+
+/// The chunks of one upload of a file's content are kept under a random id,
+/// so that a new upload never touches the content already stored. The id is
+/// followed by each chunk's big-endian index.
+pub type Upload = [u8; SUM_LEN];
+
+/// What is wrong with an entry found by `Store::verify`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    /// The entry does not match its checksum
+    Corrupt,
+
+    /// The entry's key or value cannot be decoded
+    Undecodable,
+
+    /// The entry refers to a secret or user that does not exist
+    Orphaned,
+}
+
+/// A bad entry found by `Store::verify`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Problem {
+    /// The tree holding the entry
+    pub tree: String,
+
+    /// The entry's raw key
+    pub key: Vec<u8>,
+
+    pub fault: Fault,
+    pub detail: String,
+}
+
+/// The outcome of `Store::verify`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Report {
+    /// Entries that passed every check
+    pub ok: u64,
+
+    /// Secrets older than checksums, which could only be decoded
+    pub unchecked: u64,
+
+    pub problems: Vec<Problem>,
+}
+
 /// A secret store
 pub struct Store {
     /// A database mapping from `Header`s to `EncSecret`s
     store: sled::Db,
+
+    /// User names mapped to argon2 hashes of their passwords
+    users: sled::Tree,
+
+    /// `(user, Target)` pairs mapped to the `Role` granted
+    grants: sled::Tree,
+
+    /// Token ids mapped to an argon2 hash of the token secret and its claims
+    tokens: sled::Tree,
+
+    /// The current `EncServerKey`, and the previous one while a rotation is
+    /// waiting to be confirmed
+    keys: sled::Tree,
+
+    /// The ciphertexts replaced by an unconfirmed rotation, keyed like
+    /// `store`
+    previous: sled::Tree,
+
+    /// `EncServerKey`s held besides the master key, by fingerprint
+    keyring: sled::Tree,
+
+    /// The encrypted content of file secrets in chunks, by `Upload`
+    chunks: sled::Tree,
+
+    /// The upload holding each file secret's content, by `file_key`
+    files: sled::Tree,
 }
 
 impl Store {
     /// Initialize a new store given path to database and key that locks this db's secrets
     pub fn load<P: AsRef<Path>>(db_path: P) -> Result<Self, Error> {
+        Self::from_db(sled::open(db_path)?)
+    }
+
+    fn from_db(store: sled::Db) -> Result<Self, Error> {
         Ok(Self {
-            store: sled::open(db_path)?,
+            users: store.open_tree("users")?,
+            grants: store.open_tree("grants")?,
+            tokens: store.open_tree("tokens")?,
+            keys: store.open_tree("keys")?,
+            previous: store.open_tree("previous")?,
+            keyring: store.open_tree("keyring")?,
+            chunks: store.open_tree("chunks")?,
+            files: store.open_tree("files")?,
+            store,
         })
     }
 
     // TODO make these priv
     pub(crate) fn dump(&self) {
         println!("-- dump --");
-        self.store.iter().keys().for_each(|k| {
-            println!("{:?}", bincode::deserialize::<Header>(&k.unwrap()))
+        self.store.iter().keys().for_each(|k| match k {
+            Ok(k) => println!("{:?}", bincode::deserialize::<Header>(&k)),
+            Err(e) => println!("{e}"),
         });
         println!("----------");
     }
@@ -31,68 +219,916 @@ impl Store {
         &mut self,
         secret: EncSecret,
     ) -> Result<(), Error> {
+        let key = bincode::serialize(&secret.header)?;
+        let value = encode_entry(&key, &secret)?;
         self.store
-            .insert(bincode::serialize(&secret.header)?, secret.secret)
+            .insert(key, value)
             .map(|_| ())
-            .map_err(|e| Error::Sled(e))
+            .map_err(Error::Sled)
     }
 
     pub(crate) fn get_secret(
         &self,
         secret_header: Header,
     ) -> Result<Option<EncSecret>, Error> {
-        self.store.get(bincode::serialize(&secret_header)?).map(
-            |v| match v {
-                Some(secret) => Ok(Some(EncSecret {
-                    header: secret_header,
-                    secret: secret[..].to_vec(),
-                })),
-                None => Ok(None),
-            },
-        )?
+        let key = bincode::serialize(&secret_header)?;
+        self.store
+            .get(&key)?
+            .map(|v| decode_entry(&key, secret_header, &v))
+            .transpose()
+    }
+
+    pub(crate) fn delete_secret(
+        &mut self,
+        secret_header: Header,
+    ) -> Result<Option<EncSecret>, Error> {
+        let key = bincode::serialize(&secret_header)?;
+        let removed = self.store.remove(&key)?;
+        if removed.is_some() {
+            if let Some(upload) = self.files.remove(file_key(&key))? {
+                self.delete_chunks(&upload)?;
+            }
+        }
+        removed
+            .map(|v| decode_entry(&key, secret_header, &v))
+            .transpose()
+    }
+
+    /// Start an upload of a file's content
+    pub(crate) fn new_upload(&self) -> Upload {
+        rand::random()
+    }
+
+    /// Store a chunk of an upload
+    pub(crate) fn put_chunk(
+        &mut self,
+        upload: &Upload,
+        index: u32,
+        chunk: &[u8],
+    ) -> Result<(), Error> {
+        self.chunks
+            .insert([&upload[..], &index.to_be_bytes()].concat(), chunk)?;
+        Ok(())
+    }
+
+    /// How many chunks an upload has
+    pub(crate) fn chunk_count(&self, upload: &Upload) -> Result<u32, Error> {
+        let mut count = 0;
+        for k in self.chunks.scan_prefix(upload).keys() {
+            k?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Remove the chunks of an upload that was abandoned
+    pub(crate) fn discard_chunks(
+        &mut self,
+        upload: &Upload,
+    ) -> Result<(), Error> {
+        self.delete_chunks(upload)
+    }
+
+    /// Store a file secret with `upload` as its content. The secret and its
+    /// content are swapped in together, and only then are the chunks they
+    /// replace dropped.
+    pub(crate) fn put_file(
+        &mut self,
+        secret: EncSecret,
+        upload: &Upload,
+    ) -> Result<(), Error> {
+        let key = bincode::serialize(&secret.header)?;
+        let value = encode_entry(&key, &secret)?;
+        let file = file_key(&key);
+        let old =
+            (&*self.store, &self.files).transaction(|(store, files)| {
+                store.insert(&key[..], &value[..])?;
+                Ok::<_, ConflictableTransactionError>(
+                    files.insert(&file[..], &upload[..])?,
+                )
+            })?;
+        match old {
+            Some(old) if old != upload[..] => self.delete_chunks(&old),
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether a secret has file content
+    pub(crate) fn has_file(&self, header: &Header) -> Result<bool, Error> {
+        let file = file_key(&bincode::serialize(header)?);
+        Ok(self.files.contains_key(file)?)
+    }
+
+    pub(crate) fn get_chunk(
+        &self,
+        header: &Header,
+        index: u32,
+    ) -> Result<Option<Vec<u8>>, Error> {
+        let file = file_key(&bincode::serialize(header)?);
+        let Some(upload) = self.files.get(file)? else {
+            return Ok(None);
+        };
+        Ok(self
+            .chunks
+            .get([&upload[..], &index.to_be_bytes()].concat())?
+            .map(|c| c.to_vec()))
+    }
+
+    fn delete_chunks(&mut self, prefix: &[u8]) -> Result<(), Error> {
+        for k in self.chunks.scan_prefix(prefix).keys() {
+            self.chunks.remove(k?)?;
+        }
+        Ok(())
     }
 
     // TODO This is O(n) right now. Need higher secondary indexing structure.
-    /// Will panic if internal sled db is corrupted
+    /// Entries whose header cannot be decoded are skipped
+    #[allow(dead_code)]
     pub(crate) fn get_secret_from_id(
         &self,
         secret_id: SecretID,
     ) -> Result<Option<EncSecret>, Error> {
-        self.store
-            .iter()
-            .find(|d| {
-                // idk why this is a result
-                bincode::deserialize::<Header>(&d.as_ref().unwrap().0)
-                    .unwrap()
-                    .id
-                    == secret_id
-            })
-            .map(|d| {
-                d.map(|(k, v)| EncSecret {
-                    header: bincode::deserialize::<Header>(&k).unwrap(),
-                    secret: v.to_vec(),
-                })
-            })
-            .transpose()
-            .map_err(|e| Error::Sled(e))
+        for entry in self.store.iter() {
+            let (k, v) = entry?;
+            match bincode::deserialize::<Header>(&k) {
+                Ok(header) if header.id == secret_id => {
+                    return decode_entry(&k, header, &v).map(Some)
+                }
+                _ => continue,
+            }
+        }
+        Ok(None)
     }
 
+    #[allow(dead_code)]
     pub(crate) fn get_secrets_from_label(
         &self,
-        label: &str,
+        _label: &str,
     ) -> Result<Vec<EncSecret>, Error> {
         todo!()
     }
 
-    /// Get all the secrets headers in the db
+    /// Get all the secrets headers in the db. Headers that cannot be
+    /// decoded are skipped; `verify` reports them.
     pub(crate) fn list_secrets(&self) -> Result<Vec<Header>, Error> {
+        let mut headers = Vec::new();
+        for k in self.store.iter().keys() {
+            match bincode::deserialize::<Header>(&k?) {
+                Ok(header) => headers.push(header),
+                Err(e) => warn!("skipping undecodable secret header: {e}"),
+            }
+        }
+        Ok(headers)
+    }
+
+    /// Create a user, or reset the password of an existing one
+    pub(crate) fn put_user(
+        &mut self,
+        user: &str,
+        password: &str,
+    ) -> Result<(), Error> {
+        let salt = SaltString::generate(&mut OsRng);
+        let hash = Argon2::default()
+            .hash_password(password.as_bytes(), &salt)?
+            .to_string();
+        self.users.insert(user.as_bytes(), hash.as_bytes())?;
+        Ok(())
+    }
+
+    /// Check a user's password. Unknown users never verify.
+    pub(crate) fn verify_user(
+        &self,
+        user: &str,
+        password: &str,
+    ) -> Result<bool, Error> {
+        let hash = match self.users.get(user.as_bytes())? {
+            Some(h) => h,
+            None => return Ok(false),
+        };
+        let hash = std::str::from_utf8(&hash)?;
+        Ok(Argon2::default()
+            .verify_password(password.as_bytes(), &PasswordHash::new(hash)?)
+            .is_ok())
+    }
+
+    /// Grant a role, replacing any role the user already held on the target
+    pub(crate) fn put_grant(&mut self, grant: Grant) -> Result<(), Error> {
+        if !self.users.contains_key(grant.user.as_bytes())? {
+            return Err(Error::Fail(format!("no such user {}", grant.user)));
+        }
+        self.grants.insert(
+            bincode::serialize(&(&grant.user, &grant.target))?,
+            bincode::serialize(&grant.role)?,
+        )?;
+        Ok(())
+    }
+
+    /// Remove a grant. Returns whether it existed.
+    pub(crate) fn delete_grant(
+        &mut self,
+        user: &str,
+        target: &Target,
+    ) -> Result<bool, Error> {
         Ok(self
+            .grants
+            .remove(bincode::serialize(&(user, target))?)?
+            .is_some())
+    }
+
+    /// Get every grant, or only those held by `user`
+    pub(crate) fn list_grants(
+        &self,
+        user: Option<&str>,
+    ) -> Result<Vec<Grant>, Error> {
+        // Keys are bincode `(user, target)` tuples, so a user's grants share
+        // the serialized user name as a prefix
+        let prefix = match user {
+            Some(u) => bincode::serialize(u)?,
+            None => vec![],
+        };
+        self.grants
+            .scan_prefix(prefix)
+            .map(|entry| {
+                let (k, v) = entry?;
+                let (user, target) =
+                    bincode::deserialize::<(String, Target)>(&k)?;
+                Ok(Grant {
+                    user,
+                    role: bincode::deserialize::<Role>(&v)?,
+                    target,
+                })
+            })
+            .collect()
+    }
+
+    /// Store a token's claims alongside a hash of its secret
+    pub(crate) fn put_token(
+        &mut self,
+        claims: &TokenClaims,
+        secret: &str,
+    ) -> Result<(), Error> {
+        let salt = SaltString::generate(&mut OsRng);
+        let hash = Argon2::default()
+            .hash_password(secret.as_bytes(), &salt)?
+            .to_string();
+        self.tokens.insert(
+            claims.id.as_bytes(),
+            bincode::serialize(&(hash, claims))?,
+        )?;
+        Ok(())
+    }
+
+    /// Look up a token and check its secret. Returns `None` for unknown
+    /// tokens or a wrong secret.
+    pub(crate) fn verify_token(
+        &self,
+        id: &str,
+        secret: &str,
+    ) -> Result<Option<TokenClaims>, Error> {
+        let (hash, claims) = match self.get_token(id)? {
+            Some(t) => t,
+            None => return Ok(None),
+        };
+        Ok(Argon2::default()
+            .verify_password(secret.as_bytes(), &PasswordHash::new(&hash)?)
+            .ok()
+            .map(|_| claims))
+    }
+
+    /// Get the stored hash and claims of a token
+    pub(crate) fn get_token(
+        &self,
+        id: &str,
+    ) -> Result<Option<(String, TokenClaims)>, Error> {
+        self.tokens
+            .get(id.as_bytes())?
+            .map(|t| Ok(bincode::deserialize(&t)?))
+            .transpose()
+    }
+
+    /// Remove a token. Returns whether it existed.
+    pub(crate) fn delete_token(&mut self, id: &str) -> Result<bool, Error> {
+        Ok(self.tokens.remove(id.as_bytes())?.is_some())
+    }
+
+    /// Get the key that locks the current secrets
+    pub(crate) fn master_key(&self) -> Result<Option<EncServerKey>, Error> {
+        self.keys
+            .get(CURRENT_KEY)?
+            .map(|k| EncServerKey::from_bytes(&k))
+            .transpose()
+    }
+
+    /// Get the key replaced by an unconfirmed rotation
+    pub(crate) fn previous_master_key(
+        &self,
+    ) -> Result<Option<EncServerKey>, Error> {
+        self.keys
+            .get(PREVIOUS_KEY)?
+            .map(|k| EncServerKey::from_bytes(&k))
+            .transpose()
+    }
+
+    /// Whether the store holds no secrets
+    pub(crate) fn is_empty(&self) -> bool {
+        self.store.is_empty()
+    }
+
+    /// Set the master key of a store that has none: a new store, or one
+    /// from before the master key was kept in the store, whose secrets are
+    /// encrypted to the key the server was started with. Either way the key
+    /// is adopted once, and from then on is read from here.
+    pub(crate) fn init_master_key(
+        &mut self,
+        key: &EncServerKey,
+    ) -> Result<(), Error> {
+        if self.master_key()?.is_some() {
+            return Err(Error::Fail("store already has a master key".into()));
+        }
+        self.keys.insert(CURRENT_KEY, key.to_bytes()?)?;
+        Ok(())
+    }
+
+    /// Hold another key besides the master key. It needs a fingerprint to
+    /// be asked for by.
+    pub(crate) fn add_key(
+        &mut self,
+        key: &EncServerKey,
+    ) -> Result<Fingerprint, Error> {
+        let fingerprint = key.fingerprint()?.ok_or_else(|| {
+            Error::Fail("key was locked before fingerprints".to_string())
+        })?;
+        self.keyring
+            .insert(fingerprint.as_bytes(), key.to_bytes()?)?;
+        Ok(fingerprint)
+    }
+
+    /// The public half of the master key, or of the key with `fingerprint`.
+    /// `None` if there is no such key; keys locked before public keys have
+    /// none to give.
+    pub(crate) fn public_key(
+        &self,
+        fingerprint: Option<&Fingerprint>,
+    ) -> Result<Option<PublicServerKey>, Error> {
+        let key = match fingerprint {
+            Some(fingerprint) => self.key(fingerprint)?,
+            None => self.master_key()?,
+        };
+        Ok(key.and_then(|k| k.public))
+    }
+
+    /// Get the key with the given fingerprint, whether it is the master
+    /// key, the one replaced by a pending rotation, or another
+    pub(crate) fn key(
+        &self,
+        fingerprint: &Fingerprint,
+    ) -> Result<Option<EncServerKey>, Error> {
+        for key in [self.master_key()?, self.previous_master_key()?]
+            .into_iter()
+            .flatten()
+        {
+            if key.fingerprint()? == Some(*fingerprint) {
+                return Ok(Some(key));
+            }
+        }
+        self.keyring
+            .get(fingerprint.as_bytes())?
+            .map(|k| EncServerKey::from_bytes(&k))
+            .transpose()
+    }
+
+    /// The raw keys of every secret in the store
+    fn secret_keys(tree: &sled::Tree) -> Result<BTreeSet<Vec<u8>>, Error> {
+        tree.iter().keys().map(|k| Ok(k?.to_vec())).collect()
+    }
+
+    /// The raw keys of the secrets encrypted to `master`: those tagged with
+    /// its fingerprint and those from before tags. A key without a
+    /// fingerprint claims every secret.
+    fn secrets_under(
+        &self,
+        master: &EncServerKey,
+    ) -> Result<BTreeSet<Vec<u8>>, Error> {
+        let fingerprint = match master.fingerprint()? {
+            Some(fingerprint) => fingerprint,
+            None => return Self::secret_keys(&self.store),
+        };
+        let mut keys = BTreeSet::new();
+        for entry in self.store.iter() {
+            let (k, v) = entry?;
+            let header = bincode::deserialize::<Header>(&k)?;
+            if decode_entry(&k, header, &v)?
+                .key
+                .is_none_or(|key| key == fingerprint)
+            {
+                keys.insert(k.to_vec());
+            }
+        }
+        Ok(keys)
+    }
+
+    /// Replace the master key and every ciphertext under it at once.
+    /// `secrets` must cover exactly the secrets under the current master
+    /// key; those under other keys are left alone. The old key and
+    /// ciphertexts are kept until `confirm_rotation` or `revert_rotation`.
+    pub(crate) fn rotate(
+        &mut self,
+        key: &EncServerKey,
+        secrets: Vec<EncSecret>,
+    ) -> Result<(), Error> {
+        if self.keys.contains_key(PREVIOUS_KEY)? {
+            return Err(Error::Fail(
+                "a rotation is pending: confirm or revert it first".to_string(),
+            ));
+        }
+        let secrets = secrets
+            .into_iter()
+            .map(|s| {
+                let key = bincode::serialize(&s.header)?;
+                let value = encode_entry(&key, &s)?;
+                Ok((key, value))
+            })
+            .collect::<Result<Vec<(Vec<u8>, Vec<u8>)>, Error>>()?;
+        let old_key = self
+            .master_key()?
+            .ok_or_else(|| Error::Fail("store has no master key".into()))?;
+        let new: BTreeSet<Vec<u8>> =
+            secrets.iter().map(|(k, _)| k.clone()).collect();
+        if new != self.secrets_under(&old_key)? {
+            return Err(Error::Fail(
+                "secrets changed during rotation, try again".to_string(),
+            ));
+        }
+        let old_key = old_key.to_bytes()?;
+        let new_key = key.to_bytes()?;
+
+        // Left over from a rotation that was confirmed but not cleaned up
+        self.previous.clear()?;
+        (&*self.store, &self.keys, &self.previous).transaction(
+            |(store, keys, previous)| {
+                for (k, v) in &secrets {
+                    if let Some(old) = store.insert(&k[..], &v[..])? {
+                        previous.insert(&k[..], old)?;
+                    }
+                }
+                keys.insert(PREVIOUS_KEY, old_key.clone())?;
+                keys.insert(CURRENT_KEY, &new_key[..])?;
+                Ok::<_, ConflictableTransactionError>(())
+            },
+        )?;
+        Ok(())
+    }
+
+    /// Forget the key and ciphertexts replaced by the last rotation
+    pub(crate) fn confirm_rotation(&mut self) -> Result<(), Error> {
+        if self.keys.remove(PREVIOUS_KEY)?.is_none() {
+            return Err(Error::Fail("no rotation is pending".to_string()));
+        }
+        self.previous.clear()?;
+        Ok(())
+    }
+
+    /// Undo the last rotation. Fails if secrets were added or removed since.
+    pub(crate) fn revert_rotation(&mut self) -> Result<(), Error> {
+        let old_key = self
+            .keys
+            .get(PREVIOUS_KEY)?
+            .ok_or_else(|| Error::Fail("no rotation is pending".into()))?;
+        let new_key = self
+            .master_key()?
+            .ok_or_else(|| Error::Fail("store has no master key".into()))?;
+        if Self::secret_keys(&self.previous)? != self.secrets_under(&new_key)? {
+            return Err(Error::Fail(
+                "secrets changed since the rotation, confirm it instead"
+                    .to_string(),
+            ));
+        }
+        let old = self
+            .previous
+            .iter()
+            .collect::<Result<Vec<(sled::IVec, sled::IVec)>, sled::Error>>()?;
+
+        (&*self.store, &self.keys).transaction(|(store, keys)| {
+            for (k, v) in &old {
+                store.insert(k, v)?;
+            }
+            keys.insert(CURRENT_KEY, old_key.clone())?;
+            keys.remove(PREVIOUS_KEY)?;
+            Ok::<_, ConflictableTransactionError>(())
+        })?;
+        self.previous.clear()?;
+        Ok(())
+    }
+
+    /// Check every entry of every tree, collecting what is wrong rather
+    /// than stopping at the first bad entry
+    pub(crate) fn verify(&self) -> Result<Report, Error> {
+        let mut report = Report::default();
+        let pending = self.keys.contains_key(PREVIOUS_KEY)?;
+
+        Self::check(&mut report, "secrets", &self.store, |k, v| {
+            let header = bincode::deserialize::<Header>(k)
+                .map_err(|e| (Fault::Undecodable, e.to_string()))?;
+            let checked = match checksum_ok(k, v) {
+                Some(false) => {
+                    return Err((Fault::Corrupt, header.label));
+                }
+                Some(true) => true,
+                None => false,
+            };
+            decode_entry(k, header, v)
+                .map_err(|e| (Fault::Undecodable, e.to_string()))?;
+            Ok(checked)
+        });
+
+        Self::check(&mut report, "previous", &self.previous, |k, v| {
+            let header = bincode::deserialize::<Header>(k)
+                .map_err(|e| (Fault::Undecodable, e.to_string()))?;
+            if !pending || !self.store.contains_key(k).unwrap_or(false) {
+                return Err((Fault::Orphaned, header.label));
+            }
+            if checksum_ok(k, v) == Some(false) {
+                return Err((Fault::Corrupt, header.label));
+            }
+            decode_entry(k, header, v)
+                .map_err(|e| (Fault::Undecodable, e.to_string()))?;
+            Ok(true)
+        });
+
+        Self::check(&mut report, "users", &self.users, |k, v| {
+            std::str::from_utf8(k)
+                .map_err(|e| (Fault::Undecodable, e.to_string()))?;
+            std::str::from_utf8(v)
+                .map_err(|e| e.to_string())
+                .and_then(|h| {
+                    PasswordHash::new(h).map(|_| ()).map_err(|e| e.to_string())
+                })
+                .map_err(|e| (Fault::Undecodable, e))?;
+            Ok(true)
+        });
+
+        Self::check(&mut report, "grants", &self.grants, |k, v| {
+            let (user, _) = bincode::deserialize::<(String, Target)>(k)
+                .map_err(|e| (Fault::Undecodable, e.to_string()))?;
+            bincode::deserialize::<Role>(v)
+                .map_err(|e| (Fault::Undecodable, e.to_string()))?;
+            if !self.users.contains_key(user.as_bytes()).unwrap_or(false) {
+                return Err((Fault::Orphaned, format!("no such user {user}")));
+            }
+            Ok(true)
+        });
+
+        Self::check(&mut report, "tokens", &self.tokens, |k, v| {
+            let (_, claims) = bincode::deserialize::<(String, TokenClaims)>(v)
+                .map_err(|e| (Fault::Undecodable, e.to_string()))?;
+            if k != claims.id.as_bytes() {
+                return Err((Fault::Corrupt, "token id mismatch".to_string()));
+            }
+            if !self.users.contains_key(&claims.issuer).unwrap_or(false) {
+                return Err((
+                    Fault::Orphaned,
+                    format!("no such issuer {}", claims.issuer),
+                ));
+            }
+            Ok(true)
+        });
+
+        Self::check(&mut report, "keys", &self.keys, |k, v| {
+            if k != CURRENT_KEY && k != PREVIOUS_KEY {
+                return Err((Fault::Undecodable, "unknown key".to_string()));
+            }
+            EncServerKey::from_bytes(v)
+                .map_err(|e| (Fault::Undecodable, e.to_string()))?;
+            Ok(true)
+        });
+
+        Self::check(&mut report, "keyring", &self.keyring, |k, v| {
+            let key = EncServerKey::from_bytes(v)
+                .map_err(|e| (Fault::Undecodable, e.to_string()))?;
+            match key.fingerprint() {
+                Ok(Some(fingerprint)) if fingerprint.as_bytes() == k => {
+                    Ok(true)
+                }
+                _ => Err((Fault::Corrupt, "fingerprint mismatch".to_string())),
+            }
+        });
+
+        // Chunks belong to a secret until it is deleted, or to an upload
+        // that has not finished
+        let secrets = self
             .store
             .iter()
             .keys()
-            .map(|raw_h| {
-                bincode::deserialize::<Header>(&raw_h.unwrap()).unwrap()
-            })
-            .collect::<Vec<Header>>())
+            .filter_map(|k| Some(file_key(&k.ok()?)))
+            .collect::<BTreeSet<[u8; SUM_LEN]>>();
+        Self::check(&mut report, "files", &self.files, |k, v| {
+            if k.len() != SUM_LEN || v.len() != SUM_LEN {
+                return Err((
+                    Fault::Undecodable,
+                    "malformed entry".to_string(),
+                ));
+            }
+            if !secrets.contains(k) {
+                return Err((Fault::Orphaned, "no such secret".to_string()));
+            }
+            Ok(true)
+        });
+        let uploads = self
+            .files
+            .iter()
+            .values()
+            .filter_map(|v| v.ok())
+            .collect::<BTreeSet<sled::IVec>>();
+        Self::check(&mut report, "chunks", &self.chunks, |k, _| {
+            if k.len() != SUM_LEN + 4 {
+                return Err((Fault::Undecodable, "malformed key".to_string()));
+            }
+            if !uploads.contains(&k[..SUM_LEN]) {
+                return Err((Fault::Orphaned, "no such file".to_string()));
+            }
+            Ok(true)
+        });
+
+        Ok(report)
+    }
+
+    /// Run `check` over every entry of `tree`. It returns whether the entry
+    /// was fully checked, or what is wrong with it.
+    fn check<F>(report: &mut Report, name: &str, tree: &sled::Tree, check: F)
+    where
+        F: Fn(&[u8], &[u8]) -> Result<bool, (Fault, String)>,
+    {
+        for entry in tree.iter() {
+            let (k, v) = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    // The rest of the tree cannot be read
+                    report.problems.push(Problem {
+                        tree: name.to_string(),
+                        key: vec![],
+                        fault: Fault::Undecodable,
+                        detail: e.to_string(),
+                    });
+                    return;
+                }
+            };
+            match check(&k, &v) {
+                Ok(true) => report.ok += 1,
+                Ok(false) => report.unchecked += 1,
+                Err((fault, detail)) => report.problems.push(Problem {
+                    tree: name.to_string(),
+                    key: k.to_vec(),
+                    fault,
+                    detail,
+                }),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::secret::Scope;
+
+    fn enc_key(byte: u8) -> EncServerKey {
+        EncServerKey {
+            server_key: vec![byte; 4],
+            nonce: [byte; crate::crypto::NONCE_LEN],
+            salt: [byte; crate::crypto::SALT_LEN],
+            suite: CipherSuite::X25519,
+            public: None,
+        }
+    }
+
+    fn secret(header: &Header, byte: u8) -> EncSecret {
+        EncSecret {
+            header: header.clone(),
+            secret: vec![byte; 4],
+            suite: CipherSuite::X25519,
+            signature: None,
+            key: None,
+        }
+    }
+
+    /// A key with a public half, and so a fingerprint
+    fn public_key(byte: u8) -> (EncServerKey, Fingerprint) {
+        let rsa =
+            rsa::RsaPrivateKey::new(&mut rand::thread_rng(), 512).unwrap();
+        let mut key = enc_key(byte);
+        key.public = Some(PublicServerKey {
+            rsa: rsa::RsaPublicKey::from(&rsa),
+            x25519: None,
+            verifying: None,
+            signature: None,
+        });
+        let fingerprint = key.fingerprint().unwrap().unwrap();
+        (key, fingerprint)
+    }
+
+    fn tagged(header: &Header, byte: u8, key: Fingerprint) -> EncSecret {
+        EncSecret {
+            key: Some(key),
+            ..secret(header, byte)
+        }
+    }
+
+    #[test]
+    fn test_rotation() {
+        let tmp = sled::Config::new().temporary(true).open().unwrap();
+        let mut db = Store::from_db(tmp).unwrap();
+        let a = Header::new("a", None, None, 0, Scope::Public).unwrap();
+        let b = Header::new("b", None, None, 0, Scope::Public).unwrap();
+
+        db.init_master_key(&enc_key(1)).unwrap();
+        db.put_secret(secret(&a, 1)).unwrap();
+        db.put_secret(secret(&b, 1)).unwrap();
+
+        // Every secret must be re-encrypted
+        assert!(db.rotate(&enc_key(2), vec![secret(&a, 2)]).is_err());
+        assert_eq!(db.get_secret(a.clone()).unwrap(), Some(secret(&a, 1)));
+
+        db.rotate(&enc_key(2), vec![secret(&a, 2), secret(&b, 2)])
+            .unwrap();
+        assert_eq!(db.master_key().unwrap(), Some(enc_key(2)));
+        assert_eq!(db.previous_master_key().unwrap(), Some(enc_key(1)));
+        assert_eq!(db.get_secret(b.clone()).unwrap(), Some(secret(&b, 2)));
+        assert!(db.rotate(&enc_key(3), vec![]).is_err());
+
+        db.revert_rotation().unwrap();
+        assert_eq!(db.master_key().unwrap(), Some(enc_key(1)));
+        assert_eq!(db.get_secret(b.clone()).unwrap(), Some(secret(&b, 1)));
+
+        db.rotate(&enc_key(2), vec![secret(&a, 2), secret(&b, 2)])
+            .unwrap();
+        db.confirm_rotation().unwrap();
+        assert_eq!(db.previous_master_key().unwrap(), None);
+        assert!(db.revert_rotation().is_err());
+    }
+
+    #[test]
+    fn test_keyring() {
+        let tmp = sled::Config::new().temporary(true).open().unwrap();
+        let mut db = Store::from_db(tmp).unwrap();
+        let a = Header::new("a", None, None, 0, Scope::Public).unwrap();
+        let b = Header::new("b", None, None, 0, Scope::Public).unwrap();
+        let c = Header::new("c", None, None, 0, Scope::Public).unwrap();
+        let (master, fa) = public_key(1);
+        let (team, fb) = public_key(2);
+        let (new, fc) = public_key(3);
+
+        db.init_master_key(&master).unwrap();
+        assert_eq!(db.add_key(&team).unwrap(), fb);
+        assert!(db.add_key(&enc_key(4)).is_err());
+        assert_eq!(db.key(&fa).unwrap(), Some(master.clone()));
+        assert_eq!(db.key(&fb).unwrap(), Some(team.clone()));
+        assert_eq!(db.key(&fc).unwrap(), None);
+        assert_eq!(db.public_key(None).unwrap(), master.public);
+        assert_eq!(db.public_key(Some(&fb)).unwrap(), team.public);
+        assert_eq!(db.public_key(Some(&fc)).unwrap(), None);
+
+        // Untagged secrets belong to the master key
+        db.put_secret(tagged(&a, 1, fa)).unwrap();
+        db.put_secret(tagged(&b, 1, fb)).unwrap();
+        db.put_secret(secret(&c, 1)).unwrap();
+
+        // Rotation covers the master key's secrets and only those
+        let rotated = || vec![tagged(&a, 2, fc), tagged(&c, 2, fc)];
+        let mut too_many = rotated();
+        too_many.push(tagged(&b, 2, fc));
+        assert!(db.rotate(&new, too_many).is_err());
+        assert!(db.rotate(&new, vec![tagged(&a, 2, fc)]).is_err());
+        db.rotate(&new, rotated()).unwrap();
+        assert_eq!(db.get_secret(b.clone()).unwrap(), Some(tagged(&b, 1, fb)));
+        assert_eq!(db.key(&fa).unwrap(), Some(master));
+
+        db.revert_rotation().unwrap();
+        assert_eq!(db.get_secret(c.clone()).unwrap(), Some(secret(&c, 1)));
+        assert_eq!(db.verify().unwrap().problems.len(), 0);
+    }
+
+    #[test]
+    fn test_verify() {
+        let tmp = sled::Config::new().temporary(true).open().unwrap();
+        let mut db = Store::from_db(tmp).unwrap();
+        let a = Header::new("a", None, None, 0, Scope::Public).unwrap();
+        let b = Header::new("b", None, None, 0, Scope::Public).unwrap();
+
+        db.init_master_key(&enc_key(1)).unwrap();
+        db.put_secret(secret(&a, 1)).unwrap();
+        db.put_secret(secret(&b, 1)).unwrap();
+        db.put_user("alice", "pw").unwrap();
+        db.put_grant(Grant::new("alice", Role::Reader, Target::All))
+            .unwrap();
+        let report = db.verify().unwrap();
+        assert_eq!((report.ok, report.problems.len()), (5, 0));
+
+        // Flip a ciphertext byte, and leave junk and an orphan behind
+        let key = bincode::serialize(&b).unwrap();
+        let mut value = db.store.get(&key).unwrap().unwrap().to_vec();
+        *value.last_mut().unwrap() ^= 1;
+        db.store.insert(&key, value).unwrap();
+        db.store.insert(b"junk", b"junk").unwrap();
+        db.grants
+            .insert(
+                bincode::serialize(&("bob", Target::All)).unwrap(),
+                bincode::serialize(&Role::Reader).unwrap(),
+            )
+            .unwrap();
+
+        let report = db.verify().unwrap();
+        let faults: Vec<(&str, Fault)> = report
+            .problems
+            .iter()
+            .map(|p| (p.tree.as_str(), p.fault))
+            .collect();
+        assert_eq!(report.ok, 4);
+        assert!(faults.contains(&("secrets", Fault::Corrupt)));
+        assert!(faults.contains(&("secrets", Fault::Undecodable)));
+        assert!(faults.contains(&("grants", Fault::Orphaned)));
+        assert_eq!(faults.len(), 3);
+
+        // The rest of the store stays usable
+        assert_eq!(db.list_secrets().unwrap().len(), 2);
+        assert!(db.get_secret(b).is_err());
+        assert_eq!(db.get_secret(a.clone()).unwrap(), Some(secret(&a, 1)));
+    }
+
+    #[test]
+    fn test_chunks() {
+        let tmp = sled::Config::new().temporary(true).open().unwrap();
+        let mut db = Store::from_db(tmp).unwrap();
+        let a = Header::new("a", None, None, 0, Scope::Public).unwrap();
+
+        db.init_master_key(&enc_key(1)).unwrap();
+        let upload = db.new_upload();
+        for i in 0..3 {
+            db.put_chunk(&upload, i, &[i as u8; 4]).unwrap();
+        }
+        assert_eq!(db.chunk_count(&upload).unwrap(), 3);
+        assert_eq!(db.get_chunk(&a, 0).unwrap(), None);
+        db.put_file(secret(&a, 1), &upload).unwrap();
+        assert!(db.has_file(&a).unwrap());
+        assert_eq!(db.get_chunk(&a, 2).unwrap(), Some(vec![2u8; 4]));
+        assert_eq!(db.get_chunk(&a, 3).unwrap(), None);
+
+        // Chunks belong to the whole header, not just its id
+        let forged = Header {
+            label: "b".to_string(),
+            ..a.clone()
+        };
+        assert_eq!(db.get_chunk(&forged, 0).unwrap(), None);
+        assert!(db.delete_secret(forged).unwrap().is_none());
+        assert_eq!(db.chunk_count(&upload).unwrap(), 3);
+
+        // Chunks of an unfinished upload are orphans, and discarding them
+        // leaves the stored content alone
+        let again = db.new_upload();
+        db.put_chunk(&again, 0, b"new").unwrap();
+        let report = db.verify().unwrap();
+        assert_eq!(report.problems.len(), 1);
+        assert_eq!(report.problems[0].fault, Fault::Orphaned);
+        db.discard_chunks(&again).unwrap();
+        assert_eq!(db.get_chunk(&a, 0).unwrap(), Some(vec![0u8; 4]));
+
+        // A finished upload replaces the content it was put over
+        let again = db.new_upload();
+        db.put_chunk(&again, 0, b"new").unwrap();
+        db.put_file(secret(&a, 2), &again).unwrap();
+        assert_eq!(db.get_chunk(&a, 0).unwrap(), Some(b"new".to_vec()));
+        assert_eq!(db.get_chunk(&a, 1).unwrap(), None);
+        assert_eq!(db.chunk_count(&upload).unwrap(), 0);
+        assert_eq!(db.verify().unwrap().problems.len(), 0);
+
+        db.delete_secret(a.clone()).unwrap();
+        assert!(!db.has_file(&a).unwrap());
+        assert_eq!(db.chunk_count(&again).unwrap(), 0);
+        assert_eq!(db.verify().unwrap().problems.len(), 0);
+    }
+
+    #[test]
+    fn test_legacy_entries() {
+        let tmp = sled::Config::new().temporary(true).open().unwrap();
+        let db = Store::from_db(tmp).unwrap();
+        let a = Header::new("a", None, None, 0, Scope::Public).unwrap();
+
+        // Written before entries carried a suite
+        db.store
+            .insert(bincode::serialize(&a).unwrap(), vec![1u8; 4])
+            .unwrap();
+        let legacy = db.get_secret(a.clone()).unwrap().unwrap();
+        assert_eq!(legacy.suite, CipherSuite::RsaPkcs1v15);
+        assert_eq!(legacy.secret, vec![1u8; 4]);
+
+        let mut key = enc_key(1);
+        key.suite = CipherSuite::RsaOaep;
+        db.keys
+            .insert(
+                CURRENT_KEY,
+                bincode::serialize(&(&key.server_key, key.nonce, key.salt))
+                    .unwrap(),
+            )
+            .unwrap();
+        assert_eq!(db.master_key().unwrap(), Some(key));
     }
 }
@@ -0,0 +1,227 @@
+//! Config files rendered from secrets. A template is any text with
+//! placeholders such as `{{ spark "db-prod" "password" }}`, naming a secret
+//! by its label and, for secrets with fields, the field to insert. Without a
+//! field the whole secret is inserted, which must then be plain text.
+//!
+//! Other `{{ ... }}` are left as they are, so templates for other tools pass
+//! through.
+
+use crate::primitives::payloads::Payload;
+use crate::Error;
+use std::collections::HashMap;
+use zeroize::Zeroizing;
+
+const OPEN: &str = "{{";
+const CLOSE: &str = "}}";
+const KEYWORD: &str = "spark";
+
+/// A placeholder in a template
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reference {
+    /// The label of the secret
+    pub name: String,
+    pub field: Option<String>,
+
+    /// Where the placeholder is, for errors
+    pub line: usize,
+}
+
+#[derive(Debug, PartialEq)]
+enum Piece<'a> {
+    Text(&'a str),
+    Reference(Reference),
+}
+
+/// A parsed template
+#[derive(Debug)]
+pub struct Template<'a> {
+    pieces: Vec<Piece<'a>>,
+}
+
+/// Read a double quoted string from the start of `s`, returning it and the
+/// rest of `s`. `\"` and `\\` are the only escapes.
+fn quoted(s: &str) -> Option<(String, &str)> {
+    let mut out = String::new();
+    let mut chars = s.strip_prefix('"')?.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((out, &s[i + 2..])),
+            '\\' => out.push(chars.next()?.1),
+            c => out.push(c),
+        }
+    }
+    None
+}
+
+/// Parse the arguments of a placeholder, up to and including its `}}`,
+/// returning the rest of the template
+fn arguments(mut s: &str, line: usize) -> Result<(Vec<String>, &str), Error> {
+    let mut args = vec![];
+    loop {
+        s = s.trim_start();
+        if let Some(rest) = s.strip_prefix(CLOSE) {
+            return Ok((args, rest));
+        }
+        match quoted(s) {
+            Some((arg, rest)) => {
+                args.push(arg);
+                s = rest;
+            }
+            None => {
+                return Err(Error::Fail(format!(
+                    "line {line}: expected a quoted string or }}}} in \
+                     placeholder"
+                )))
+            }
+        }
+    }
+}
+
+impl<'a> Template<'a> {
+    pub fn parse(text: &'a str) -> Result<Self, Error> {
+        let mut pieces = vec![];
+        let mut rest = text;
+        // Text scanned past that is not yet in a piece
+        let mut start = 0;
+        while let Some(i) = rest[start..].find(OPEN) {
+            let open = start + i;
+            let inner = rest[open + OPEN.len()..].trim_start();
+            let ours = inner.strip_prefix(KEYWORD).is_some_and(|r| {
+                r.starts_with(char::is_whitespace) || r.starts_with(['"', '}'])
+            });
+            if !ours {
+                start = open + OPEN.len();
+                continue;
+            }
+
+            let line = text.len() - rest.len() + open;
+            let line = text[..line].matches('\n').count() + 1;
+            let (args, after) = arguments(&inner[KEYWORD.len()..], line)?;
+            let mut args = args.into_iter();
+            let reference = match (args.next(), args.next(), args.next()) {
+                (Some(name), field, None) => Reference { name, field, line },
+                _ => {
+                    return Err(Error::Fail(format!(
+                        "line {line}: expected {{{{ spark \"NAME\" \
+                         [\"FIELD\"] }}}}"
+                    )))
+                }
+            };
+            if open > 0 {
+                pieces.push(Piece::Text(&rest[..open]));
+            }
+            pieces.push(Piece::Reference(reference));
+            rest = after;
+            start = 0;
+        }
+        if !rest.is_empty() {
+            pieces.push(Piece::Text(rest));
+        }
+        Ok(Self { pieces })
+    }
+
+    pub fn references(&self) -> impl Iterator<Item = &Reference> {
+        self.pieces.iter().filter_map(|p| match p {
+            Piece::Reference(r) => Some(r),
+            Piece::Text(_) => None,
+        })
+    }
+
+    /// The labels of every secret the template refers to, each once, in the
+    /// order they first appear
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = vec![];
+        for r in self.references() {
+            if !names.contains(&r.name.as_str()) {
+                names.push(&r.name);
+            }
+        }
+        names
+    }
+
+    /// Fill in the placeholders from `secrets`, by label
+    pub fn render(
+        &self,
+        secrets: &HashMap<String, Payload>,
+    ) -> Result<Zeroizing<String>, Error> {
+        let mut out = Zeroizing::new(String::new());
+        for piece in &self.pieces {
+            match piece {
+                Piece::Text(text) => out.push_str(text),
+                Piece::Reference(r) => {
+                    let fail = |e: Error| {
+                        let e = match e {
+                            Error::Fail(message) => message,
+                            e => e.to_string(),
+                        };
+                        Error::Fail(format!(
+                            "line {}: \"{}\": {e}",
+                            r.line, r.name
+                        ))
+                    };
+                    let payload = secrets.get(&r.name).ok_or_else(|| {
+                        fail(Error::Fail("no such secret".to_string()))
+                    })?;
+                    let value = match &r.field {
+                        Some(field) => payload.field(field),
+                        None => payload.text(),
+                    };
+                    out.push_str(value.map_err(fail)?);
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::payloads::{CredentialsPayload, GenericPayload};
+
+    #[test]
+    fn test_template() {
+        let text = "host: db\n\
+                    user: {{ spark \"db-prod\" \"username\" }}\n\
+                    pass: {{spark \"db-prod\" \"password\"}}\n\
+                    token: {{ spark \"api \\\"key\\\"\" }} {{ .Values.x }}\n";
+        let template = Template::parse(text).unwrap();
+        assert_eq!(template.names(), ["db-prod", "api \"key\""]);
+        assert_eq!(template.references().nth(2).unwrap().line, 4);
+
+        let mut secrets = HashMap::new();
+        secrets.insert(
+            "db-prod".to_string(),
+            Payload::Credentials(CredentialsPayload::new("db", "app", "s3}}")),
+        );
+        secrets.insert(
+            "api \"key\"".to_string(),
+            Payload::Generic(GenericPayload::new(b"tok".to_vec())),
+        );
+        assert_eq!(
+            *template.render(&secrets).unwrap(),
+            "host: db\nuser: app\npass: s3}}\ntoken: tok {{ .Values.x }}\n"
+        );
+
+        // Missing secrets and fields, and secrets that need a field
+        secrets.remove("api \"key\"");
+        let err = template.render(&secrets).unwrap_err().to_string();
+        assert!(err.contains("line 4") && err.contains("no such secret"));
+        let bad = |t| Template::parse(t).unwrap().render(&secrets).is_err();
+        assert!(bad("{{ spark \"db-prod\" \"pin\" }}"));
+        assert!(bad("{{ spark \"db-prod\" }}"));
+
+        // Malformed placeholders
+        for t in [
+            "{{ spark }}",
+            "{{ spark db }}",
+            "{{ spark \"a\" \"b\" \"c\" }}",
+            "{{ spark \"a\"",
+            "{{ spark \"a }}",
+        ] {
+            assert!(Template::parse(t).is_err(), "{t}");
+        }
+        assert_eq!(Template::parse("{{ sparkle }}").unwrap().names().len(), 0);
+        assert!(Template::parse("{{spark}}").is_err());
+    }
+}
@@ -0,0 +1,334 @@
+//! Conversions between crate types and their capnp representations
+
+use super::access::{Grant, Op, Role, Target};
+use super::store::{Fault, Problem, Report};
+use crate::crypto::suite::CipherSuite;
+use crate::crypto::types::{EncServerKey, Fingerprint, PublicServerKey};
+use crate::crypto::{NONCE_LEN, SALT_LEN};
+use crate::primitives::secret::{EncSecret, Header, Scope, SecretID, Tag};
+use crate::protocol_capnp::keyserver;
+use crate::Error;
+use ed25519_dalek::{Signature, VerifyingKey};
+use rsa::pkcs8::{DecodePublicKey, EncodePublicKey};
+use rsa::RsaPublicKey;
+use std::net::Ipv4Addr;
+use x25519_dalek::PublicKey;
+
+pub fn tag_to_wire(tag: &Tag) -> keyserver::Tag {
+    match tag {
+        Tag::APIKey => keyserver::Tag::Apikey,
+        Tag::PublicKey => keyserver::Tag::Publickey,
+        Tag::PrivateKey => keyserver::Tag::Privatekey,
+        Tag::Keypair => keyserver::Tag::Keypair,
+        Tag::Credentials => keyserver::Tag::Credentials,
+        Tag::Other => keyserver::Tag::Other,
+        Tag::SshKey => keyserver::Tag::Sshkey,
+        Tag::Certificate => keyserver::Tag::Certificate,
+    }
+}
+
+pub fn tag_from_wire(tag: keyserver::Tag) -> Tag {
+    match tag {
+        keyserver::Tag::Apikey => Tag::APIKey,
+        keyserver::Tag::Publickey => Tag::PublicKey,
+        keyserver::Tag::Privatekey => Tag::PrivateKey,
+        keyserver::Tag::Keypair => Tag::Keypair,
+        keyserver::Tag::Credentials => Tag::Credentials,
+        keyserver::Tag::Other => Tag::Other,
+        keyserver::Tag::Sshkey => Tag::SshKey,
+        keyserver::Tag::Certificate => Tag::Certificate,
+    }
+}
+
+pub fn suite_to_wire(suite: CipherSuite) -> keyserver::CipherSuite {
+    match suite {
+        CipherSuite::RsaPkcs1v15 => keyserver::CipherSuite::RsaPkcs1v15,
+        CipherSuite::RsaOaep => keyserver::CipherSuite::RsaOaep,
+        CipherSuite::X25519 => keyserver::CipherSuite::X25519,
+    }
+}
+
+pub fn suite_from_wire(suite: keyserver::CipherSuite) -> CipherSuite {
+    match suite {
+        keyserver::CipherSuite::RsaPkcs1v15 => CipherSuite::RsaPkcs1v15,
+        keyserver::CipherSuite::RsaOaep => CipherSuite::RsaOaep,
+        keyserver::CipherSuite::X25519 => CipherSuite::X25519,
+    }
+}
+
+pub fn write_header(mut b: keyserver::header::Builder, header: &Header) {
+    b.set_id(&header.id.bytes()[..]);
+    b.set_label(&header.label);
+    if let Some(desc) = &header.desc {
+        b.set_desc(desc);
+    }
+    if let Some(tag) = &header.tag {
+        b.set_has_tag(true);
+        b.set_tag(tag_to_wire(tag));
+    }
+    {
+        let mut ts = b.reborrow().init_creation();
+        ts.set_upper((header.creation >> 64) as u64);
+        ts.set_lower(header.creation as u64);
+    }
+    b.set_expiration(header.expiration);
+    match &header.scope {
+        Scope::Public => b.set_scope(keyserver::Scope::Public),
+        Scope::Local => b.set_scope(keyserver::Scope::Local),
+        Scope::Custom(ips) => {
+            b.set_scope(keyserver::Scope::Custom);
+            let mut list = b.init_ips(ips.len() as u32);
+            ips.iter()
+                .enumerate()
+                .for_each(|(i, ip)| list.set(i as u32, u32::from(*ip)));
+        }
+    }
+}
+
+pub fn read_header(r: keyserver::header::Reader) -> Result<Header, Error> {
+    let creation = r.get_creation()?;
+    let scope = match r.get_scope()? {
+        keyserver::Scope::Public => Scope::Public,
+        keyserver::Scope::Local => Scope::Local,
+        keyserver::Scope::Custom => {
+            Scope::Custom(r.get_ips()?.iter().map(Ipv4Addr::from).collect())
+        }
+    };
+    Ok(Header {
+        id: SecretID::from_vec(r.get_id()?)?,
+        label: r.get_label()?.to_string(),
+        desc: match r.has_desc() {
+            true => Some(r.get_desc()?.to_string()),
+            false => None,
+        },
+        tag: match r.get_has_tag() {
+            true => Some(tag_from_wire(r.get_tag()?)),
+            false => None,
+        },
+        creation: ((creation.get_upper() as u128) << 64)
+            | creation.get_lower() as u128,
+        expiration: r.get_expiration(),
+        scope,
+    })
+}
+
+pub fn write_public_key(
+    mut b: keyserver::public_key::Builder,
+    key: &PublicServerKey,
+) -> Result<(), Error> {
+    let rsa = key
+        .rsa
+        .to_public_key_der()
+        .map_err(|e| Error::Fail(format!("encoding public key: {e}")))?;
+    b.set_rsa(rsa.as_ref());
+    if let Some(x25519) = &key.x25519 {
+        b.set_x25519(x25519.as_bytes());
+    }
+    if let Some(verifying) = &key.verifying {
+        b.set_verifying(verifying.as_bytes());
+    }
+    if let Some(sig) = &key.signature {
+        b.set_signature(&sig.to_bytes());
+    }
+    Ok(())
+}
+
+pub fn read_public_key(
+    r: keyserver::public_key::Reader,
+) -> Result<PublicServerKey, Error> {
+    let malformed = || Error::Fail("malformed public key".to_string());
+    let array =
+        |bytes: &[u8]| <[u8; 32]>::try_from(bytes).map_err(|_| malformed());
+    Ok(PublicServerKey {
+        rsa: RsaPublicKey::from_public_key_der(r.get_rsa()?)
+            .map_err(|_| malformed())?,
+        x25519: match r.has_x25519() {
+            true => Some(PublicKey::from(array(r.get_x25519()?)?)),
+            false => None,
+        },
+        verifying: match r.has_verifying() {
+            true => Some(
+                VerifyingKey::from_bytes(&array(r.get_verifying()?)?)
+                    .map_err(|_| malformed())?,
+            ),
+            false => None,
+        },
+        signature: match r.has_signature() {
+            true => Some(
+                Signature::from_slice(r.get_signature()?)
+                    .map_err(|_| malformed())?,
+            ),
+            false => None,
+        },
+    })
+}
+
+pub fn write_master_key(
+    mut b: keyserver::master_key::Builder,
+    key: &EncServerKey,
+) -> Result<(), Error> {
+    b.set_rawkey(&key.server_key[..]);
+    b.set_nonce(&key.nonce);
+    b.set_salt(&key.salt);
+    b.set_suite(suite_to_wire(key.suite));
+    if let Some(public) = &key.public {
+        write_public_key(b.init_public(), public)?;
+    }
+    Ok(())
+}
+
+pub fn read_master_key(
+    r: keyserver::master_key::Reader,
+) -> Result<EncServerKey, Error> {
+    let (nonce, salt) = (r.get_nonce()?, r.get_salt()?);
+    if nonce.len() != NONCE_LEN || salt.len() != SALT_LEN {
+        return Err(Error::Fail("malformed master key".to_string()));
+    }
+    let mut key = EncServerKey {
+        server_key: r.get_rawkey()?.to_vec(),
+        nonce: [0u8; NONCE_LEN],
+        salt: [0u8; SALT_LEN],
+        suite: suite_from_wire(r.get_suite()?),
+        public: match r.has_public() {
+            true => Some(read_public_key(r.get_public()?)?),
+            false => None,
+        },
+    };
+    key.nonce.copy_from_slice(nonce);
+    key.salt.copy_from_slice(salt);
+    Ok(key)
+}
+
+pub fn write_secret(mut b: keyserver::secret::Builder, secret: &EncSecret) {
+    write_header(b.reborrow().init_header(), &secret.header);
+    b.set_rawsecret(&secret.secret[..]);
+    b.set_suite(suite_to_wire(secret.suite));
+    if let Some(sig) = &secret.signature {
+        b.set_signature(&sig.to_bytes());
+    }
+    if let Some(key) = &secret.key {
+        b.set_key(key.as_bytes());
+    }
+}
+
+pub fn read_secret(r: keyserver::secret::Reader) -> Result<EncSecret, Error> {
+    Ok(EncSecret {
+        header: read_header(r.get_header()?)?,
+        secret: r.get_rawsecret()?.to_vec(),
+        suite: suite_from_wire(r.get_suite()?),
+        signature: match r.has_signature() {
+            true => Some(
+                Signature::from_slice(r.get_signature()?)
+                    .map_err(|_| Error::Fail("malformed signature".into()))?,
+            ),
+            false => None,
+        },
+        key: match r.has_key() {
+            true => Some(Fingerprint::from_slice(r.get_key()?)?),
+            false => None,
+        },
+    })
+}
+
+pub fn write_grant(mut b: keyserver::grant::Builder, grant: &Grant) {
+    b.set_user(&grant.user);
+    b.set_role(match grant.role {
+        Role::Reader => keyserver::Role::Reader,
+        Role::Writer => keyserver::Role::Writer,
+        Role::Admin => keyserver::Role::Admin,
+    });
+    let mut t = b.init_target();
+    match &grant.target {
+        Target::All => t.set_kind(keyserver::TargetKind::All),
+        Target::Prefix(p) => {
+            t.set_kind(keyserver::TargetKind::Prefix);
+            t.set_prefix(p);
+        }
+        Target::Tag(tag) => {
+            t.set_kind(keyserver::TargetKind::Tag);
+            t.set_tag(tag_to_wire(tag));
+        }
+    }
+}
+
+pub fn read_grant(r: keyserver::grant::Reader) -> Result<Grant, Error> {
+    let t = r.get_target()?;
+    Ok(Grant {
+        user: r.get_user()?.to_string(),
+        role: match r.get_role()? {
+            keyserver::Role::Reader => Role::Reader,
+            keyserver::Role::Writer => Role::Writer,
+            keyserver::Role::Admin => Role::Admin,
+        },
+        target: match t.get_kind()? {
+            keyserver::TargetKind::All => Target::All,
+            keyserver::TargetKind::Prefix => {
+                Target::Prefix(t.get_prefix()?.to_string())
+            }
+            keyserver::TargetKind::Tag => {
+                Target::Tag(tag_from_wire(t.get_tag()?))
+            }
+        },
+    })
+}
+
+pub fn op_to_wire(op: Op) -> Result<keyserver::Op, Error> {
+    match op {
+        Op::GetMaster => Ok(keyserver::Op::GetMaster),
+        Op::List => Ok(keyserver::Op::List),
+        Op::Get => Ok(keyserver::Op::Get),
+        Op::Put => Ok(keyserver::Op::Put),
+        Op::Delete => Ok(keyserver::Op::Delete),
+        Op::Admin => Err(Error::Fail("tokens cannot administer".to_string())),
+    }
+}
+
+pub fn op_from_wire(op: keyserver::Op) -> Op {
+    match op {
+        keyserver::Op::GetMaster => Op::GetMaster,
+        keyserver::Op::List => Op::List,
+        keyserver::Op::Get => Op::Get,
+        keyserver::Op::Put => Op::Put,
+        keyserver::Op::Delete => Op::Delete,
+    }
+}
+
+pub fn write_report(mut b: keyserver::report::Builder, report: &Report) {
+    b.set_ok(report.ok);
+    b.set_unchecked(report.unchecked);
+    let mut list = b.init_problems(report.problems.len() as u32);
+    for (i, problem) in report.problems.iter().enumerate() {
+        let mut p = list.reborrow().get(i as u32);
+        p.set_tree(&problem.tree);
+        p.set_key(&problem.key);
+        p.set_fault(match problem.fault {
+            Fault::Corrupt => keyserver::Fault::Corrupt,
+            Fault::Undecodable => keyserver::Fault::Undecodable,
+            Fault::Orphaned => keyserver::Fault::Orphaned,
+        });
+        p.set_detail(&problem.detail);
+    }
+}
+
+pub fn read_report(r: keyserver::report::Reader) -> Result<Report, Error> {
+    Ok(Report {
+        ok: r.get_ok(),
+        unchecked: r.get_unchecked(),
+        problems: r
+            .get_problems()?
+            .iter()
+            .map(|p| {
+                Ok(Problem {
+                    tree: p.get_tree()?.to_string(),
+                    key: p.get_key()?.to_vec(),
+                    fault: match p.get_fault()? {
+                        keyserver::Fault::Corrupt => Fault::Corrupt,
+                        keyserver::Fault::Undecodable => Fault::Undecodable,
+                        keyserver::Fault::Orphaned => Fault::Orphaned,
+                    },
+                    detail: p.get_detail()?.to_string(),
+                })
+            })
+            .collect::<Result<Vec<Problem>, Error>>()?,
+    })
+}
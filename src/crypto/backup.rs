@@ -0,0 +1,196 @@
+//! Paper backups of an encrypted server key: armored text, or numbered lines
+//! of words for copying by hand. Both carry a checksum, so that a damaged or
+//! mistyped backup is caught before it is imported.
+
+use crate::Error;
+use base64ct::{Base64, Encoding};
+use bip39::Language;
+use sha2::{Digest, Sha256};
+
+const BEGIN: &str = "-----BEGIN SPARK SERVER KEY-----";
+const END: &str = "-----END SPARK SERVER KEY-----";
+const LINE_LEN: usize = 64;
+const CHECK_LEN: usize = 4;
+
+/// Each word of the BIP-39 list stands for 11 bits
+const WORD_BITS: u32 = 11;
+const WORDS_PER_LINE: usize = 8;
+
+fn checksum(data: &[u8]) -> [u8; CHECK_LEN] {
+    let mut sum = [0u8; CHECK_LEN];
+    sum.copy_from_slice(&Sha256::digest(data)[..CHECK_LEN]);
+    sum
+}
+
+fn mismatch() -> Error {
+    Error::Fail("backup checksum does not match, check for typos".into())
+}
+
+/// Encode as base64 between BEGIN and END lines, with a final `=` line
+/// holding the checksum
+pub fn armor(data: &[u8]) -> String {
+    let body = Base64::encode_string(data);
+    let mut out = format!("{BEGIN}\n");
+    for line in body.as_bytes().chunks(LINE_LEN) {
+        out.push_str(&String::from_utf8_lossy(line));
+        out.push('\n');
+    }
+    out.push_str(&format!(
+        "={}\n{END}\n",
+        Base64::encode_string(&checksum(data))
+    ));
+    out
+}
+
+/// Decode `armor` output, ignoring anything around the BEGIN and END lines
+pub fn dearmor(text: &str) -> Result<Vec<u8>, Error> {
+    let malformed = || Error::Fail("malformed armored key".to_string());
+    let mut lines = text
+        .lines()
+        .map(str::trim)
+        .skip_while(|l| *l != BEGIN)
+        .skip(1)
+        .take_while(|l| *l != END)
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<&str>>();
+    let sum = lines
+        .pop()
+        .and_then(|l| l.strip_prefix('='))
+        .ok_or_else(malformed)?;
+    let sum = Base64::decode_vec(sum).map_err(|_| malformed())?;
+    let data = Base64::decode_vec(&lines.concat()).map_err(|_| malformed())?;
+    if sum != checksum(&data) {
+        return Err(mismatch());
+    }
+    Ok(data)
+}
+
+/// Encode as numbered lines of words. The words cover the length, the data
+/// and its checksum.
+pub fn to_words(data: &[u8]) -> String {
+    let list = Language::English.word_list();
+    let mut payload = (data.len() as u32).to_be_bytes().to_vec();
+    payload.extend(data);
+    payload.extend(checksum(data));
+
+    let mut words = Vec::new();
+    let (mut acc, mut bits) = (0u32, 0u32);
+    for byte in payload {
+        acc = (acc << 8) | byte as u32;
+        bits += 8;
+        while bits >= WORD_BITS {
+            bits -= WORD_BITS;
+            words.push(list[(acc >> bits) as usize & 0x7ff]);
+        }
+        acc &= (1 << bits) - 1;
+    }
+    if bits > 0 {
+        // Pad the last word with zeros
+        words.push(list[(acc << (WORD_BITS - bits)) as usize & 0x7ff]);
+    }
+
+    words
+        .chunks(WORDS_PER_LINE)
+        .enumerate()
+        .map(|(i, line)| format!("{:>4}: {}\n", i + 1, line.join(" ")))
+        .collect()
+}
+
+/// Look up a word, or a unique prefix of at least four letters, which is
+/// all BIP-39 needs to tell words apart
+fn find_word(word: &str) -> Option<u16> {
+    let english = Language::English;
+    english
+        .find_word(word)
+        .or_else(|| match english.words_by_prefix(word) {
+            [only] if word.len() >= 4 => english.find_word(only),
+            _ => None,
+        })
+}
+
+/// Decode `to_words` output. Line numbers are optional.
+pub fn from_words(text: &str) -> Result<Vec<u8>, Error> {
+    let mut bytes = Vec::new();
+    let (mut acc, mut bits) = (0u32, 0u32);
+    for (n, line) in text.lines().enumerate() {
+        let words = line.split_once(':').map_or(line, |(_, w)| w);
+        for word in words.split_whitespace() {
+            let index = find_word(&word.to_lowercase()).ok_or_else(|| {
+                Error::Fail(format!(
+                    "unknown word \"{word}\" on line {}",
+                    n + 1
+                ))
+            })?;
+            acc = (acc << WORD_BITS) | index as u32;
+            bits += WORD_BITS;
+            while bits >= 8 {
+                bits -= 8;
+                bytes.push((acc >> bits) as u8);
+            }
+            acc &= (1 << bits) - 1;
+        }
+    }
+
+    if bytes.len() < 4 {
+        return Err(mismatch());
+    }
+    let (len, rest) = bytes.split_at(4);
+    let len = u32::from_be_bytes([len[0], len[1], len[2], len[3]]) as usize;
+    // At most one byte of padding follows the checksum
+    if rest.len() < len + CHECK_LEN || rest.len() > len + CHECK_LEN + 1 {
+        return Err(mismatch());
+    }
+    let (data, sum) = rest.split_at(len);
+    if sum[..CHECK_LEN] != checksum(data) {
+        return Err(mismatch());
+    }
+    Ok(data.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_armor() {
+        let data: Vec<u8> = (0..200).collect();
+        let text = armor(&data);
+        assert_eq!(dearmor(&format!("notes\n{text}more")).unwrap(), data);
+
+        // Change the first character of the body
+        let mut lines: Vec<String> = text.lines().map(String::from).collect();
+        let first = match lines[1].starts_with('A') {
+            true => "B",
+            false => "A",
+        };
+        lines[1].replace_range(..1, first);
+        assert!(dearmor(&lines.join("\n")).is_err());
+        assert!(dearmor("no key here").is_err());
+    }
+
+    #[test]
+    fn test_words() {
+        for len in [0, 1, 3, 7, 100] {
+            let data: Vec<u8> = (0..len).map(|i| (i * 37) as u8).collect();
+            assert_eq!(from_words(&to_words(&data)).unwrap(), data);
+        }
+
+        let data = b"the encrypted key".to_vec();
+        let words = to_words(&data);
+        // Case, numbering and four letter prefixes do not matter
+        let loose = words
+            .lines()
+            .flat_map(|l| l.split_once(':').unwrap().1.split_whitespace())
+            .map(|w| w[..w.len().min(4)].to_uppercase())
+            .collect::<Vec<String>>()
+            .join(" ");
+        assert_eq!(from_words(&loose).unwrap(), data);
+
+        // Swapping two different words breaks the checksum
+        let mut swapped: Vec<&str> = loose.split(' ').collect();
+        let other = (1..swapped.len()).find(|&i| swapped[i] != swapped[0]);
+        swapped.swap(0, other.unwrap());
+        assert!(from_words(&swapped.join(" ")).is_err());
+        assert!(from_words("notaword").is_err());
+    }
+}
@@ -0,0 +1,178 @@
+//! Random passwords and diceware-style passphrases for `CredentialsPayload`s,
+//! so passwords can be born in the vault instead of typed on a command line.
+
+use crate::Error;
+use bip39::Language;
+use rand::seq::SliceRandom;
+use zeroize::Zeroizing;
+
+/// Every printable ASCII symbol, the default for sites with no rules
+pub const SYMBOLS: &str = "!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~";
+
+/// A class of characters a password is drawn from
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Class {
+    Lower,
+    Upper,
+    Digit,
+    Symbol,
+}
+
+/// What a site wants of a password
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rules {
+    pub length: usize,
+
+    /// Classes to draw from, each of which appears at least once
+    pub classes: Vec<Class>,
+
+    /// The symbols the site accepts
+    pub symbols: String,
+
+    /// Characters the site refuses, or that are too easily misread
+    pub exclude: String,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            length: 20,
+            classes: vec![
+                Class::Lower,
+                Class::Upper,
+                Class::Digit,
+                Class::Symbol,
+            ],
+            symbols: SYMBOLS.to_string(),
+            exclude: String::new(),
+        }
+    }
+}
+
+impl Rules {
+    fn chars(&self, class: Class) -> Vec<char> {
+        let chars: Vec<char> = match class {
+            Class::Lower => ('a'..='z').collect(),
+            Class::Upper => ('A'..='Z').collect(),
+            Class::Digit => ('0'..='9').collect(),
+            Class::Symbol => self.symbols.chars().collect(),
+        };
+        chars
+            .into_iter()
+            .filter(|c| !self.exclude.contains(*c))
+            .collect()
+    }
+
+    /// Generate a password. Passwords missing a class are thrown away and
+    /// drawn again rather than patched, which would make them less random.
+    pub fn password(&self) -> Result<Zeroizing<String>, Error> {
+        if self.classes.is_empty() {
+            return Err(Error::Fail(
+                "no character classes to draw from".into(),
+            ));
+        }
+        let classes: Vec<Vec<char>> =
+            self.classes.iter().map(|c| self.chars(*c)).collect();
+        if let Some(i) = classes.iter().position(Vec::is_empty) {
+            return Err(Error::Fail(format!(
+                "every {:?} character is excluded",
+                self.classes[i]
+            )));
+        }
+        if self.length < classes.len() {
+            return Err(Error::Fail(format!(
+                "{} characters cannot hold {} classes",
+                self.length,
+                classes.len()
+            )));
+        }
+        let mut pool: Vec<char> = classes.concat();
+        pool.sort_unstable();
+        pool.dedup();
+
+        let mut rng = rand::thread_rng();
+        loop {
+            let password: Zeroizing<String> = Zeroizing::new(
+                (0..self.length)
+                    .map(|_| *pool.choose(&mut rng).unwrap())
+                    .collect(),
+            );
+            if classes
+                .iter()
+                .all(|class| password.chars().any(|c| class.contains(&c)))
+            {
+                return Ok(password);
+            }
+        }
+    }
+}
+
+/// Generate a passphrase of `words` words from the BIP-39 English list, 11
+/// bits each
+pub fn passphrase(
+    words: usize,
+    separator: &str,
+) -> Result<Zeroizing<String>, Error> {
+    if words == 0 {
+        return Err(Error::Fail("a passphrase needs words".into()));
+    }
+    let list = Language::English.word_list();
+    let mut rng = rand::thread_rng();
+    let words: Vec<&str> = (0..words)
+        .map(|_| *list.choose(&mut rng).unwrap())
+        .collect();
+    Ok(Zeroizing::new(words.join(separator)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        let rules = Rules {
+            length: 12,
+            symbols: "!-".to_string(),
+            exclude: "lI1O0".to_string(),
+            ..Rules::default()
+        };
+        for _ in 0..20 {
+            let password = rules.password().unwrap();
+            assert_eq!(password.chars().count(), 12);
+            assert!(password.chars().any(|c| c.is_ascii_lowercase()));
+            assert!(password.chars().any(|c| c.is_ascii_uppercase()));
+            assert!(password.chars().any(|c| c.is_ascii_digit()));
+            assert!(password.chars().any(|c| "!-".contains(c)));
+            assert!(!password.chars().any(|c| "lI1O0".contains(c)));
+        }
+        let digits = Rules {
+            length: 6,
+            classes: vec![Class::Digit],
+            ..Rules::default()
+        };
+        assert!(digits
+            .password()
+            .unwrap()
+            .chars()
+            .all(|c| c.is_ascii_digit()));
+
+        // Rules no password can meet
+        let too_short = Rules {
+            length: 3,
+            ..Rules::default()
+        };
+        assert!(too_short.password().is_err());
+        let no_digits = Rules {
+            exclude: "0123456789".to_string(),
+            ..Rules::default()
+        };
+        assert!(no_digits.password().is_err());
+
+        let phrase = passphrase(6, " ").unwrap();
+        let words: Vec<&str> = phrase.split(' ').collect();
+        assert_eq!(words.len(), 6);
+        let list = Language::English.word_list();
+        assert!(words.iter().all(|w| list.contains(w)));
+        assert!(passphrase(0, "-").is_err());
+    }
+}
@@ -1,20 +1,22 @@
+pub mod backup;
+pub mod generate;
+pub mod passphrase;
+pub mod pem;
+pub mod policy;
+pub mod shares;
+pub mod ssh;
+pub mod stream;
+pub mod suite;
 pub mod types;
 
-use super::primitives::payloads::Payload;
 use super::primitives::secret::{EncSecret, Secret};
-use crate::{Error, GeneralError};
-use aes_gcm::aead::{Aead, NewAead};
-use aes_gcm::{Aes256Gcm, Key, Nonce};
-use argon2::{
-    password_hash::{
-        rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier,
-        SaltString,
-    },
-    Argon2, Params,
-};
-use std::io;
-use std::io::Write;
+use crate::Error;
+use aes_gcm::aead::NewAead;
+use aes_gcm::{Aes256Gcm, Key};
+use argon2::{password_hash::PasswordHasher, Argon2, Params};
 use std::str;
+use types::Fingerprint;
+use zeroize::Zeroizing;
 
 pub const SALT_LEN: usize = 16;
 pub const HASH_LEN: usize = 32;
@@ -24,51 +26,33 @@ pub const KEY_SIZE: usize = 2048;
 /// A type with the ability to encrypt and decrypt secrets. Functions
 /// in this trait are to be run client-side.
 pub trait Encryptor {
+    /// The fingerprint of the key secrets are encrypted to
+    fn fingerprint(&self) -> Result<Fingerprint, Error>;
     fn encrypt(&self, sec: Secret) -> Result<EncSecret, Error>;
     fn decrypt(&self, sec: EncSecret) -> Result<Secret, Error>;
 }
 
 /// Hash input data with a given salt using Argon2
-pub fn a2_hash(data: Vec<u8>, salt: [u8; SALT_LEN]) -> Result<Vec<u8>, Error> {
+pub fn a2_hash(
+    data: &[u8],
+    salt: [u8; SALT_LEN],
+) -> Result<Zeroizing<Vec<u8>>, Error> {
     let ctx = Argon2::from(Params::new(4096u32, 3u32, 1u32, Some(HASH_LEN))?);
-    match ctx
-        .hash_password(&data[..], str::from_utf8(&salt[..])?)?
-        .hash
-    {
-        Some(h) => Ok(h.as_bytes().to_vec()),
+    match ctx.hash_password(data, str::from_utf8(&salt[..])?)?.hash {
+        Some(h) => Ok(Zeroizing::new(h.as_bytes().to_vec())),
         None => Err(Error::Fail("error hashing with argon2".to_string())),
     }
 }
 
-/// Read a passphrase from the user
-fn read_passphrase(confirm: bool) -> Result<String, Error> {
-    print!("Enter passphrase: ");
-    io::stdout().flush()?;
-    let phrase1 = rpassword::read_password()?;
-    let mut phrase2 = phrase1.clone();
-    if confirm {
-        print!("Confirm passphrase: ");
-        io::stdout().flush()?;
-        phrase2 = rpassword::read_password()?;
-    }
-
-    if phrase1 != phrase2 {
-        Err(Error::Fail("Passphrases do not match".to_string()))
-    } else {
-        Ok(phrase1)
-    }
-}
-
-/// Derive a key from a passphrase supplied by stdin
+/// Derive a key from a passphrase
 // pub fn derive_key(salt: [u8; SALT_LEN]) -> (Aes256Gcm, Nonce<u8>) {
 // TODO Fix this return type to return `Nonce`
 pub fn derive_key(
     salt: [u8; SALT_LEN],
-    confirm: bool,
+    phrase: &str,
 ) -> Result<Aes256Gcm, Error> {
-    // Read user passphrase from stdin and expand
-    let phrase = read_passphrase(confirm)?;
-    let expanded = a2_hash(Vec::from(phrase.as_bytes()), salt)?;
+    // Expand the passphrase
+    let expanded = a2_hash(phrase.as_bytes(), salt)?;
 
     // The nonce (96 bits) is the a2 hash of the salt (16 byte)
     let key = Key::from_slice(&expanded[..]);
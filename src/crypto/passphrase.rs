@@ -0,0 +1,230 @@
+//! Sources of the passphrase that locks a `ServerKey`
+
+use crate::Error;
+use std::fmt;
+use std::fs;
+use std::io::Read;
+use std::mem::ManuallyDrop;
+use std::os::unix::io::FromRawFd;
+use std::path::PathBuf;
+use std::process;
+use zeroize::{Zeroize, Zeroizing};
+
+/// The longest passphrase read from a file descriptor
+const MAX_LEN: usize = 1024;
+
+/// Something that can supply the passphrase for a `ServerKey`
+pub trait PassphraseProvider {
+    /// Get the passphrase. When `confirm` is set and the source is
+    /// interactive, the passphrase is asked for twice.
+    fn passphrase(&self, confirm: bool) -> Result<Zeroizing<String>, Error>;
+}
+
+/// Prompt on the terminal
+pub struct Tty;
+
+/// Read the passphrase from an environment variable
+pub struct Env(pub String);
+
+/// Read a line from an already open file descriptor, like `gpg
+/// --passphrase-fd`
+pub struct Fd(pub i32);
+
+/// Read the first line of a file
+pub struct File(pub PathBuf);
+
+/// Run an askpass-style program with the prompt as its last argument and
+/// read the passphrase from its stdout
+pub struct Command(pub String);
+
+/// Build a provider from a source spec: `tty`, `env:VAR`, `fd:N`,
+/// `file:PATH` or `cmd:PROGRAM [ARGS...]`
+pub fn parse(spec: &str) -> Result<Box<dyn PassphraseProvider>, Error> {
+    let (kind, arg) = spec.split_once(':').unwrap_or((spec, ""));
+    match (kind, arg) {
+        ("tty", "") => Ok(Box::new(Tty)),
+        ("env", var) if !var.is_empty() => Ok(Box::new(Env(var.to_string()))),
+        ("fd", fd) => match fd.parse() {
+            Ok(fd) => Ok(Box::new(Fd(fd))),
+            Err(_) => Err(Error::Fail(format!("invalid fd \"{fd}\""))),
+        },
+        ("file", path) if !path.is_empty() => {
+            Ok(Box::new(File(PathBuf::from(path))))
+        }
+        ("cmd", cmd) if !cmd.trim().is_empty() => {
+            Ok(Box::new(Command(cmd.to_string())))
+        }
+        _ => Err(Error::Fail(format!(
+            "invalid passphrase source \"{spec}\": expected tty, env:VAR, \
+             fd:N, file:PATH or cmd:PROGRAM"
+        ))),
+    }
+}
+
+/// Strip a single trailing newline (and carriage return), keeping any other
+/// whitespace that is part of the passphrase
+fn chomp(mut line: Zeroizing<String>) -> Zeroizing<String> {
+    if line.ends_with('\n') {
+        line.pop();
+        if line.ends_with('\r') {
+            line.pop();
+        }
+    }
+    line
+}
+
+/// Turn bytes into a passphrase without copying them, wiping them if they
+/// are not valid utf-8
+fn from_utf8(
+    bytes: Vec<u8>,
+    source: impl fmt::Display,
+) -> Result<Zeroizing<String>, Error> {
+    match String::from_utf8(bytes) {
+        Ok(phrase) => Ok(Zeroizing::new(phrase)),
+        Err(e) => {
+            e.into_bytes().zeroize();
+            Err(Error::Fail(format!("{source} gave a non utf-8 passphrase")))
+        }
+    }
+}
+
+/// Ask twice on the terminal if `confirm` is set, and check both agree
+fn confirmed<F>(confirm: bool, mut ask: F) -> Result<Zeroizing<String>, Error>
+where
+    F: FnMut(&str) -> Result<Zeroizing<String>, Error>,
+{
+    let phrase1 = ask("Enter passphrase: ")?;
+    if confirm && *phrase1 != *ask("Confirm passphrase: ")? {
+        return Err(Error::Fail("Passphrases do not match".to_string()));
+    }
+    Ok(phrase1)
+}
+
+impl PassphraseProvider for Tty {
+    fn passphrase(&self, confirm: bool) -> Result<Zeroizing<String>, Error> {
+        // Prompted for on the terminal itself, not stdout, which may be
+        // redirected to a file
+        confirmed(confirm, |prompt| {
+            Ok(Zeroizing::new(rpassword::prompt_password(prompt)?))
+        })
+    }
+}
+
+impl PassphraseProvider for Env {
+    fn passphrase(&self, _: bool) -> Result<Zeroizing<String>, Error> {
+        std::env::var(&self.0)
+            .map(Zeroizing::new)
+            .map_err(|e| Error::Fail(format!("reading ${}: {e}", self.0)))
+    }
+}
+
+impl PassphraseProvider for Fd {
+    fn passphrase(&self, _: bool) -> Result<Zeroizing<String>, Error> {
+        // The descriptor belongs to whoever opened it, so it must not be
+        // closed here. Read a byte at a time to leave anything after the
+        // first line for the next call.
+        let mut file =
+            ManuallyDrop::new(unsafe { fs::File::from_raw_fd(self.0) });
+        // Reserve up front so the buffer is never reallocated (and copied)
+        let mut line = Zeroizing::new(Vec::with_capacity(MAX_LEN));
+        let mut byte = Zeroizing::new([0u8; 1]);
+        while file.read(&mut byte[..])? == 1 && byte[0] != b'\n' {
+            if line.len() == MAX_LEN {
+                return Err(Error::Fail(format!("fd {} too long", self.0)));
+            }
+            line.push(byte[0]);
+        }
+        // Lines written on Windows end in CRLF
+        if line.last() == Some(&b'\r') {
+            line.pop();
+        }
+        from_utf8(std::mem::take(&mut *line), format_args!("fd {}", self.0))
+    }
+}
+
+impl PassphraseProvider for File {
+    fn passphrase(&self, _: bool) -> Result<Zeroizing<String>, Error> {
+        let mut contents = Zeroizing::new(fs::read_to_string(&self.0)?);
+        // Keep only the first line, in place
+        if let Some(end) = contents.find('\n') {
+            contents.truncate(end + 1);
+        }
+        Ok(chomp(contents))
+    }
+}
+
+impl PassphraseProvider for Command {
+    fn passphrase(&self, confirm: bool) -> Result<Zeroizing<String>, Error> {
+        let mut words = self.0.split_whitespace();
+        let program = words
+            .next()
+            .ok_or_else(|| Error::Fail("empty passphrase command".into()))?;
+        let args: Vec<&str> = words.collect();
+
+        confirmed(confirm, |prompt| {
+            let out = process::Command::new(program)
+                .args(&args)
+                .arg(prompt)
+                .stdin(process::Stdio::inherit())
+                .stderr(process::Stdio::inherit())
+                .output()?;
+            let mut stdout = Zeroizing::new(out.stdout);
+            if !out.status.success() {
+                return Err(Error::Fail(format!(
+                    "{program} exited with {}",
+                    out.status
+                )));
+            }
+            Ok(chomp(from_utf8(std::mem::take(&mut *stdout), program)?))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::os::unix::io::AsRawFd;
+
+    #[test]
+    fn test_parse() {
+        assert!(parse("tty").is_ok());
+        assert!(parse("env:SPARK_PASSPHRASE").is_ok());
+        assert!(parse("fd:3").is_ok());
+        assert!(parse("file:/tmp/pass").is_ok());
+        assert!(parse("cmd:pass show spark").is_ok());
+        assert!(parse("env:").is_err());
+        assert!(parse("fd:three").is_err());
+        assert!(parse("keyring").is_err());
+    }
+
+    #[test]
+    fn test_sources() {
+        std::env::set_var("SPARK_TEST_PASSPHRASE", "from env");
+        let env = Env("SPARK_TEST_PASSPHRASE".to_string());
+        assert_eq!(*env.passphrase(true).unwrap(), "from env");
+
+        let path = std::env::temp_dir().join("spark_test_passphrase");
+        fs::File::create(&path)
+            .unwrap()
+            .write_all(b" from file \nignored\n")
+            .unwrap();
+        assert_eq!(
+            *File(path.clone()).passphrase(false).unwrap(),
+            " from file "
+        );
+        fs::remove_file(&path).unwrap();
+
+        fs::write(&path, b"from fd\r\nnext\n").unwrap();
+        let file = fs::File::open(&path).unwrap();
+        let fd = Fd(file.as_raw_fd());
+        assert_eq!(*fd.passphrase(false).unwrap(), "from fd");
+        assert_eq!(*fd.passphrase(false).unwrap(), "next");
+        fs::remove_file(path).unwrap();
+
+        let cmd = Command("echo from".to_string());
+        assert_eq!(*cmd.passphrase(false).unwrap(), "from Enter passphrase: ");
+        // The prompts differ, so echoing them back never confirms
+        assert!(cmd.passphrase(true).is_err());
+    }
+}
@@ -0,0 +1,481 @@
+//! Rules for the passphrases that lock server keys. A locked key is handed
+//! to anyone allowed to `getMaster`, who can then guess at its passphrase
+//! offline and at their own pace, so the passphrase is checked before a key
+//! is written rather than trusted.
+//!
+//! Strength is estimated in the spirit of zxcvbn: the passphrase is split
+//! into the cheapest run of pieces an attacker would guess, such as common
+//! passwords, dictionary words, repeats, sequences and single characters,
+//! and the guesses for each piece are added up.
+
+use crate::crypto::passphrase::PassphraseProvider;
+use crate::Error;
+use bip39::Language;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use zeroize::Zeroizing;
+
+/// Common passwords, most common first. The rank of a match is how many
+/// guesses it is assumed to take.
+const COMMON: &[&str] = &[
+    "123456",
+    "password",
+    "12345678",
+    "qwerty",
+    "123456789",
+    "12345",
+    "1234",
+    "111111",
+    "1234567",
+    "dragon",
+    "123123",
+    "baseball",
+    "abc123",
+    "football",
+    "monkey",
+    "letmein",
+    "696969",
+    "shadow",
+    "master",
+    "666666",
+    "qwertyuiop",
+    "123321",
+    "mustang",
+    "1234567890",
+    "michael",
+    "654321",
+    "superman",
+    "1qaz2wsx",
+    "7777777",
+    "121212",
+    "000000",
+    "qazwsx",
+    "123qwe",
+    "killer",
+    "trustno1",
+    "jordan",
+    "jennifer",
+    "zxcvbnm",
+    "asdfgh",
+    "hunter",
+    "buster",
+    "soccer",
+    "harley",
+    "batman",
+    "andrew",
+    "tigger",
+    "sunshine",
+    "iloveyou",
+    "2000",
+    "charlie",
+    "robert",
+    "thomas",
+    "hockey",
+    "ranger",
+    "daniel",
+    "starwars",
+    "112233",
+    "george",
+    "computer",
+    "michelle",
+    "jessica",
+    "pepper",
+    "1111",
+    "zxcvbn",
+    "555555",
+    "11111111",
+    "131313",
+    "freedom",
+    "777777",
+    "pass",
+    "maggie",
+    "159753",
+    "aaaaaa",
+    "ginger",
+    "princess",
+    "joshua",
+    "cheese",
+    "amanda",
+    "summer",
+    "love",
+    "ashley",
+    "nicole",
+    "chelsea",
+    "biteme",
+    "matthew",
+    "access",
+    "yankees",
+    "987654321",
+    "dallas",
+    "austin",
+    "thunder",
+    "taylor",
+    "matrix",
+    "minecraft",
+    "welcome",
+    "admin",
+    "login",
+    "changeme",
+    "secret",
+    "whatever",
+    "qwerty123",
+    "password1",
+    "1q2w3e4r",
+    "zaq12wsx",
+    "abcdef",
+    "abcd1234",
+    "default",
+    "root",
+    "toor",
+    "test",
+    "guest",
+    "hello",
+    "trustme",
+    "passphrase",
+    "letmein1",
+    "monkey1",
+    "football1",
+    "iloveyou1",
+    "princess1",
+    "qwertyui",
+    "asdfghjkl",
+    "1qazxsw2",
+    "q1w2e3r4",
+    "azerty",
+    "solo",
+    "loveme",
+    "flower",
+    "hottie",
+    "lovely",
+    "babygirl",
+    "123abc",
+    "samsung",
+    "apple",
+    "google",
+    "spark",
+    "keyserver",
+    "vault",
+    "correcthorsebatterystaple",
+];
+
+/// Characters commonly swapped for letters, and the letters they stand for
+const LEET: &[(char, char)] = &[
+    ('0', 'o'),
+    ('1', 'i'),
+    ('3', 'e'),
+    ('4', 'a'),
+    ('5', 's'),
+    ('7', 't'),
+    ('@', 'a'),
+    ('$', 's'),
+    ('!', 'i'),
+];
+
+/// What a passphrase must be to lock a key
+#[derive(Debug, Clone, PartialEq)]
+pub struct Policy {
+    /// The fewest characters allowed
+    pub min_length: usize,
+
+    /// The fewest bits of guessing allowed, as estimated by `estimate`
+    pub min_bits: f64,
+
+    /// Words no passphrase may contain, such as the organisation's name,
+    /// on top of the built in list of common passwords
+    pub blocklist: Vec<String>,
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Self {
+            min_length: 12,
+            min_bits: 50.0,
+            blocklist: vec![],
+        }
+    }
+}
+
+/// Command line options for a `Policy`, shared by the binaries that lock
+/// keys. Each overrides the default it names.
+#[derive(clap::Args, Debug, Clone)]
+pub struct PolicyArgs {
+    #[arg(long, help = "The fewest characters a new passphrase may have")]
+    pub min_passphrase_length: Option<usize>,
+    #[arg(
+        long,
+        help = "The fewest bits of guessing a new passphrase may take"
+    )]
+    pub min_passphrase_bits: Option<f64>,
+    #[arg(
+        long,
+        help = "A file of words, one per line, no new passphrase may contain"
+    )]
+    pub passphrase_blocklist: Option<PathBuf>,
+}
+
+impl PolicyArgs {
+    /// The policy these options ask for, reading the blocklist if given
+    pub fn policy(&self) -> Result<Policy, Error> {
+        let default = Policy::default();
+        let blocklist = match &self.passphrase_blocklist {
+            Some(path) => fs::read_to_string(path)?
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(str::to_string)
+                .collect(),
+            None => vec![],
+        };
+        Ok(Policy {
+            min_length: self
+                .min_passphrase_length
+                .unwrap_or(default.min_length),
+            min_bits: self.min_passphrase_bits.unwrap_or(default.min_bits),
+            blocklist,
+        })
+    }
+}
+
+/// Why a passphrase falls short of a `Policy`
+#[derive(Debug, Clone, PartialEq)]
+pub enum Weakness {
+    TooShort { length: usize, min: usize },
+    Common,
+    Blocked(String),
+    Guessable { bits: f64, min: f64 },
+}
+
+impl fmt::Display for Weakness {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Weakness::TooShort { length, min } => {
+                write!(f, "it is {length} characters, fewer than {min}")
+            }
+            Weakness::Common => write!(f, "it is a common password"),
+            Weakness::Blocked(word) => {
+                write!(f, "it contains \"{word}\", which is blocklisted")
+            }
+            Weakness::Guessable { bits, min } => write!(
+                f,
+                "it would take about 2^{bits:.0} guesses, fewer than \
+                 2^{min:.0}"
+            ),
+        }
+    }
+}
+
+impl Policy {
+    /// Everything wrong with a passphrase under this policy
+    pub fn weaknesses(&self, passphrase: &str) -> Vec<Weakness> {
+        let mut found = vec![];
+        let length = passphrase.chars().count();
+        if length < self.min_length {
+            found.push(Weakness::TooShort {
+                length,
+                min: self.min_length,
+            });
+        }
+        if rank(&unleet(&passphrase.to_lowercase())).is_some() {
+            found.push(Weakness::Common);
+        }
+        let lower = passphrase.to_lowercase();
+        for word in &self.blocklist {
+            let word = word.trim().to_lowercase();
+            if !word.is_empty()
+                && (lower.contains(&word) || unleet(&lower).contains(&word))
+            {
+                found.push(Weakness::Blocked(word));
+            }
+        }
+        let bits = estimate(passphrase);
+        if bits < self.min_bits {
+            found.push(Weakness::Guessable {
+                bits,
+                min: self.min_bits,
+            });
+        }
+        found
+    }
+
+    /// Accept a passphrase, or say everything that is wrong with it
+    pub fn check(&self, passphrase: &str) -> Result<(), Error> {
+        let found = self.weaknesses(passphrase);
+        if found.is_empty() {
+            return Ok(());
+        }
+        let reasons: Vec<String> =
+            found.iter().map(|w| w.to_string()).collect();
+        Err(Error::Fail(format!(
+            "passphrase rejected: {}",
+            reasons.join("; ")
+        )))
+    }
+}
+
+/// A `PassphraseProvider` that holds the passphrases it is asked to confirm,
+/// which are the ones being chosen for a key, to a `Policy`
+pub struct Checked<'a> {
+    pub provider: &'a dyn PassphraseProvider,
+    pub policy: &'a Policy,
+}
+
+impl PassphraseProvider for Checked<'_> {
+    fn passphrase(&self, confirm: bool) -> Result<Zeroizing<String>, Error> {
+        let passphrase = self.provider.passphrase(confirm)?;
+        if confirm {
+            self.policy.check(&passphrase)?;
+        }
+        Ok(passphrase)
+    }
+}
+
+/// The rank of a common password, counting from one
+fn rank(word: &str) -> Option<usize> {
+    COMMON.iter().position(|c| *c == word).map(|i| i + 1)
+}
+
+/// Undo common letter substitutions
+fn unleet(word: &str) -> String {
+    word.chars()
+        .map(|c| LEET.iter().find(|(l, _)| *l == c).map_or(c, |(_, r)| *r))
+        .collect()
+}
+
+/// Bits to guess one character by brute force, from the size of its class.
+/// Word separators are among a handful people reach for.
+fn char_bits(c: char) -> f64 {
+    let class = match c {
+        ' ' | '-' | '_' | '.' => 4.0,
+        '0'..='9' => 10.0,
+        'a'..='z' | 'A'..='Z' => 26.0,
+        ' '..='~' => 33.0,
+        _ => 100.0,
+    };
+    f64::log2(class)
+}
+
+/// Extra bits for the capitals in a word matched in lower case: one when
+/// only the first letter or every letter is a capital, as people usually
+/// do, and one per capital otherwise
+fn case_bits(piece: &[char]) -> f64 {
+    let upper = piece.iter().filter(|c| c.is_uppercase()).count();
+    let letters = piece.iter().filter(|c| c.is_alphabetic()).count();
+    match upper {
+        0 => 0.0,
+        1 if piece[0].is_uppercase() => 1.0,
+        n if n == letters => 1.0,
+        n => n as f64,
+    }
+}
+
+/// Bits to guess `piece` as a single pattern, if it forms one
+fn pattern_bits(piece: &[char]) -> Option<f64> {
+    let len = piece.len();
+    if len < 3 {
+        return None;
+    }
+    let lower: String = piece.iter().collect::<String>().to_lowercase();
+    let plain = unleet(&lower);
+    let substituted = if plain != lower { 1.0 } else { 0.0 };
+
+    let mut best: Option<f64> = None;
+    let mut consider = |bits: f64| {
+        best = Some(best.map_or(bits, |b: f64| b.min(bits)));
+    };
+    if let Some(r) = rank(&lower).or_else(|| rank(&plain)) {
+        // Even the most common password is a guess of its own
+        let guesses = f64::log2(r as f64).max(1.0);
+        consider(guesses + case_bits(piece) + substituted);
+    }
+    if Language::English.find_word(&plain).is_some() {
+        // A word from a 2048 word list
+        consider(11.0 + case_bits(piece) + substituted);
+    }
+    if piece.iter().all(|c| *c == piece[0]) {
+        consider(char_bits(piece[0]) + f64::log2(len as f64));
+    }
+    let steps: Vec<i64> = piece
+        .windows(2)
+        .map(|w| w[1] as i64 - w[0] as i64)
+        .collect();
+    if steps.iter().all(|s| *s == 1) || steps.iter().all(|s| *s == -1) {
+        let descending = if steps[0] < 0 { 1.0 } else { 0.0 };
+        consider(char_bits(piece[0]) + f64::log2(len as f64) + descending);
+    }
+    best
+}
+
+/// Estimate the bits of guessing a passphrase takes: the cheapest way to
+/// build it from patterns and single characters
+pub fn estimate(passphrase: &str) -> f64 {
+    let chars: Vec<char> = passphrase.chars().collect();
+    // cheapest[i] is the cost of the first i characters
+    let mut cheapest = vec![f64::INFINITY; chars.len() + 1];
+    cheapest[0] = 0.0;
+    for end in 1..=chars.len() {
+        let mut best = cheapest[end - 1] + char_bits(chars[end - 1]);
+        for start in 0..end.saturating_sub(2) {
+            if let Some(bits) = pattern_bits(&chars[start..end]) {
+                best = best.min(cheapest[start] + bits);
+            }
+        }
+        cheapest[end] = best;
+    }
+    cheapest[chars.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy() {
+        let policy = Policy {
+            blocklist: vec!["Acme".to_string()],
+            ..Policy::default()
+        };
+        assert!(policy.check("").is_err());
+        assert_eq!(
+            policy.weaknesses("P@ssw0rd"),
+            vec![
+                Weakness::TooShort { length: 8, min: 12 },
+                Weakness::Common,
+                Weakness::Guessable {
+                    bits: estimate("P@ssw0rd"),
+                    min: 50.0
+                },
+            ]
+        );
+        // Long is not enough when it is made of guessable pieces
+        assert!(estimate("aaaaaaaaaaaaaaaaaaaa") < 20.0);
+        assert!(estimate("abcdefghijklmnop") < 20.0);
+        assert!(estimate("Password123456") < 30.0);
+        assert!(policy.check("Password123456").is_err());
+
+        // Words from the list count for 11 bits each
+        let words = "absurd wrist oyster nephew galaxy";
+        assert!((50.0..70.0).contains(&estimate(words)));
+        assert!(policy.check(words).is_ok());
+        assert!(policy.check("absurd acme oyster nephew galaxy").is_err());
+        assert!(policy.check("x7#Kq9!vLm2$Rp").is_ok());
+        let err = policy.check("acme").unwrap_err().to_string();
+        assert!(err.contains("blocklisted") && err.contains("fewer than 12"));
+    }
+
+    #[test]
+    fn test_policy_args() {
+        let path = std::env::temp_dir().join("spark_test_blocklist");
+        fs::write(&path, "acme\n\n  \nwidgets\n").unwrap();
+        let args = PolicyArgs {
+            min_passphrase_length: Some(20),
+            min_passphrase_bits: None,
+            passphrase_blocklist: Some(path.clone()),
+        };
+        let policy = args.policy().unwrap();
+        assert_eq!(policy.min_length, 20);
+        assert_eq!(policy.min_bits, Policy::default().min_bits);
+        assert_eq!(policy.blocklist, ["acme", "widgets"]);
+        fs::remove_file(&path).unwrap();
+        assert!(args.policy().is_err());
+    }
+}
@@ -0,0 +1,232 @@
+//! Splitting a secret into Shamir shares, any `threshold` of which recover
+//! it. Shares are single lines of text so they can be printed or copied:
+//! `spark-share-<threshold>-<check>-<hex>`, where `check` identifies the
+//! secret so that shares of different secrets are not mixed.
+//!
+//! Each byte of the secret is the constant term of its own polynomial over
+//! GF(2^8), whose other coefficients are drawn uniformly, zero included, so
+//! that fewer than `threshold` shares say nothing about it. A share's hex is
+//! its x coordinate followed by one y per byte of the secret.
+
+use crate::Error;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use zeroize::Zeroizing;
+
+const PREFIX: &str = "spark-share";
+const CHECK_LEN: usize = 4;
+
+/// The low bits of the field's reducing polynomial, x^8 + x^4 + x^3 + x^2 +
+/// 1, the one shares have always been made with
+const POLY: u8 = 0x1d;
+
+/// Multiply in GF(2^8), without tables or branches so that the time taken
+/// does not depend on the secret
+fn mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0;
+    for _ in 0..8 {
+        product ^= a & (b & 1).wrapping_neg();
+        a = (a << 1) ^ (POLY & (a >> 7).wrapping_neg());
+        b >>= 1;
+    }
+    product
+}
+
+/// The inverse of a non-zero element, as a^254
+fn inv(a: u8) -> u8 {
+    let mut result = 1;
+    let mut power = a;
+    for bit in 0..8 {
+        if 254 >> bit & 1 == 1 {
+            result = mul(result, power);
+        }
+        power = mul(power, power);
+    }
+    result
+}
+
+/// One point on each byte's polynomial
+struct Share {
+    x: u8,
+    y: Zeroizing<Vec<u8>>,
+}
+
+/// A short hash identifying the secret a share belongs to
+fn check(secret: &[u8]) -> String {
+    hex::encode(&Sha256::digest(secret)[..CHECK_LEN])
+}
+
+/// Split `secret` into `count` shares, any `threshold` of which recover it
+pub fn split(
+    secret: &[u8],
+    threshold: u8,
+    count: u8,
+) -> Result<Vec<Zeroizing<String>>, Error> {
+    if threshold < 2 || threshold > count {
+        return Err(Error::Fail(format!(
+            "threshold must be between 2 and the number of shares, not \
+             {threshold}"
+        )));
+    }
+    if secret.is_empty() {
+        return Err(Error::Fail("nothing to split".to_string()));
+    }
+    // The coefficients above the constant term, for every byte
+    let degree = threshold as usize - 1;
+    let mut coefficients = Zeroizing::new(vec![0u8; secret.len() * degree]);
+    rand::thread_rng().fill_bytes(&mut coefficients);
+
+    let check = check(secret);
+    Ok((1..=count)
+        .map(|x| {
+            let mut bytes =
+                Zeroizing::new(Vec::with_capacity(secret.len() + 1));
+            bytes.push(x);
+            for (byte, poly) in secret.iter().zip(coefficients.chunks(degree)) {
+                // Horner's rule, from the highest coefficient down
+                let y = poly.iter().rev().fold(0, |acc, c| mul(acc, x) ^ c);
+                bytes.push(mul(y, x) ^ byte);
+            }
+            Zeroizing::new(format!(
+                "{PREFIX}-{threshold}-{check}-{}",
+                hex::encode(&bytes[..])
+            ))
+        })
+        .collect())
+}
+
+/// The secret at x = 0, by Lagrange interpolation through `shares`
+fn interpolate(shares: &[Share]) -> Zeroizing<Vec<u8>> {
+    let mut secret = Zeroizing::new(vec![0u8; shares[0].y.len()]);
+    for (i, share) in shares.iter().enumerate() {
+        // The basis polynomial of this share at 0. Subtraction is xor.
+        let basis = shares
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .fold(1, |acc, (_, other)| {
+                mul(acc, mul(other.x, inv(other.x ^ share.x)))
+            });
+        for (byte, y) in secret.iter_mut().zip(share.y.iter()) {
+            *byte ^= mul(basis, *y);
+        }
+    }
+    secret
+}
+
+/// Parse a share, returning its threshold and check alongside it
+fn parse(share: &str) -> Result<(u8, &str, Share), Error> {
+    let malformed = || Error::Fail("malformed share".to_string());
+    let rest = share.trim().strip_prefix(PREFIX).ok_or_else(malformed)?;
+    let mut parts = rest.strip_prefix('-').ok_or_else(malformed)?.split('-');
+    let (threshold, check, data) =
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(t), Some(c), Some(d), None) => (t, c, d),
+            _ => return Err(malformed()),
+        };
+    let threshold = threshold.parse().map_err(|_| malformed())?;
+    let mut bytes = Zeroizing::new(hex::decode(data).map_err(|_| malformed())?);
+    match bytes.first() {
+        Some(&x) if x != 0 && bytes.len() > 1 => {
+            bytes.remove(0);
+            Ok((threshold, check, Share { x, y: bytes }))
+        }
+        _ => Err(malformed()),
+    }
+}
+
+/// Recover a secret from at least `threshold` of its shares
+pub fn combine<S: AsRef<str>>(
+    shares: &[S],
+) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let mut threshold = None;
+    let mut check = None;
+    let mut parsed = Vec::with_capacity(shares.len());
+    let mut seen = HashSet::new();
+    for share in shares {
+        let (t, c, share) = parse(share.as_ref())?;
+        if *threshold.get_or_insert(t) != t || *check.get_or_insert(c) != c {
+            return Err(Error::Fail(
+                "shares belong to different secrets".to_string(),
+            ));
+        }
+        // Duplicates add nothing
+        if seen.insert(share.x) {
+            parsed.push(share);
+        }
+    }
+    let threshold =
+        threshold.ok_or_else(|| Error::Fail("no shares given".to_string()))?;
+    if parsed.len() < threshold as usize {
+        return Err(Error::Fail(format!(
+            "{} of {threshold} shares needed",
+            parsed.len()
+        )));
+    }
+
+    if parsed
+        .iter()
+        .any(|share| share.y.len() != parsed[0].y.len())
+    {
+        return Err(Error::Fail("shares belong to different secrets".into()));
+    }
+
+    let secret = interpolate(&parsed);
+    if Some(self::check(&secret).as_str()) != check {
+        return Err(Error::Fail("shares do not recover the secret".into()));
+    }
+    Ok(secret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shares() {
+        let secret = b"the master key".to_vec();
+        let shares = split(&secret, 3, 5).unwrap();
+        assert_eq!(shares.len(), 5);
+
+        assert_eq!(*combine(&shares[..3]).unwrap(), secret);
+        assert_eq!(*combine(&shares[2..]).unwrap(), secret);
+        assert!(combine(&shares[..2]).is_err());
+        // The same share twice does not count twice
+        assert!(combine(&[&shares[0], &shares[0], &shares[1]]).is_err());
+
+        let other = split(b"another key!!!", 3, 5).unwrap();
+        assert!(combine(&[&shares[0], &shares[1], &other[2]]).is_err());
+
+        // Change the last byte of one share
+        let mut tampered = shares[..3].to_vec();
+        let last = match tampered[0].ends_with('0') {
+            true => '1',
+            false => '0',
+        };
+        tampered[0].pop();
+        tampered[0].push(last);
+        assert!(combine(&tampered).is_err());
+
+        assert!(split(&secret, 1, 5).is_err());
+        assert!(split(&secret, 4, 3).is_err());
+        assert!(split(b"", 2, 3).is_err());
+
+        // Shares made before the split was done here still recover
+        let old = [
+            "spark-share-2-e56d305f-01d32ac2071036386506721ac34306c384b5",
+            "spark-share-2-e56d305f-03b6a69349d68a8a4bdc706ebe0fccbc5b2a",
+        ];
+        assert_eq!(*combine(&old).unwrap(), b"old sharks secret");
+    }
+
+    #[test]
+    fn test_field() {
+        assert_eq!(mul(0x80, 2), POLY);
+        assert_eq!(mul(0x53, 0), 0);
+        for a in 1..=255 {
+            assert_eq!(mul(a, inv(a)), 1);
+            assert_eq!(mul(a, 1), a);
+        }
+    }
+}
@@ -0,0 +1,223 @@
+//! Chunked authenticated encryption for content too large to seal at once,
+//! following the STREAM construction (Hoang et al., "Online
+//! Authenticated-Encryption and its Nonce-Reuse Misuse-Resistance").
+//!
+//! Each chunk is sealed with AES-256-GCM under a nonce made of a random
+//! prefix, the chunk's position and whether it is the last. Chunks that are
+//! reordered, dropped or cut off at the end fail to open, so a stream is
+//! only ever read back whole and in order.
+
+use crate::crypto::NONCE_LEN;
+use crate::Error;
+use aes_gcm::aead::{Aead, NewAead};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use std::io::{self, Read};
+use zeroize::Zeroizing;
+
+/// The plaintext bytes in every chunk but the last
+pub const CHUNK_SIZE: u32 = 64 << 10;
+
+pub const KEY_LEN: usize = 32;
+pub const PREFIX_LEN: usize = 7;
+
+/// The number of chunks `size` bytes take. Even nothing takes one, so that
+/// there is always a last chunk to find.
+pub fn chunks(size: u64, chunk_size: u32) -> u64 {
+    size.div_ceil(chunk_size as u64).max(1)
+}
+
+/// One direction of a stream: chunks are sealed or opened in order
+pub struct Stream {
+    cipher: Aes256Gcm,
+    prefix: [u8; PREFIX_LEN],
+    counter: u32,
+    finished: bool,
+}
+
+impl Stream {
+    pub fn new(key: &[u8], prefix: &[u8]) -> Result<Self, Error> {
+        if key.len() != KEY_LEN || prefix.len() != PREFIX_LEN {
+            return Err(Error::Fail("malformed stream key".to_string()));
+        }
+        Ok(Self {
+            cipher: Aes256Gcm::new(Key::from_slice(key)),
+            prefix: prefix.try_into().unwrap(),
+            counter: 0,
+            finished: false,
+        })
+    }
+
+    /// The nonce of the next chunk, which moves the stream along
+    fn next_nonce(&mut self, last: bool) -> Result<[u8; NONCE_LEN], Error> {
+        if self.finished {
+            return Err(Error::Fail("stream already ended".to_string()));
+        }
+        let mut nonce = [0u8; NONCE_LEN];
+        nonce[..PREFIX_LEN].copy_from_slice(&self.prefix);
+        nonce[PREFIX_LEN..NONCE_LEN - 1]
+            .copy_from_slice(&self.counter.to_be_bytes());
+        nonce[NONCE_LEN - 1] = last as u8;
+        self.counter = match self.counter.checked_add(1) {
+            Some(counter) => counter,
+            None if last => self.counter,
+            None => return Err(Error::Fail("stream too long".to_string())),
+        };
+        self.finished = last;
+        Ok(nonce)
+    }
+
+    pub fn seal(&mut self, chunk: &[u8], last: bool) -> Result<Vec<u8>, Error> {
+        let nonce = self.next_nonce(last)?;
+        Ok(self.cipher.encrypt(Nonce::from_slice(&nonce), chunk)?)
+    }
+
+    pub fn open(
+        &mut self,
+        chunk: &[u8],
+        last: bool,
+    ) -> Result<Zeroizing<Vec<u8>>, Error> {
+        let index = self.counter;
+        let nonce = self.next_nonce(last)?;
+        let plain = self
+            .cipher
+            .decrypt(Nonce::from_slice(&nonce), chunk)
+            .map_err(|_| Error::Fail(format!("chunk {index} is corrupt")))?;
+        Ok(Zeroizing::new(plain))
+    }
+
+    /// Whether the last chunk has been sealed or opened
+    pub fn finished(&self) -> bool {
+        self.finished
+    }
+}
+
+/// Seals what a reader holds, one chunk at a time. The reader must hold
+/// exactly `size` bytes, as promised to whoever opens the stream.
+pub struct Sealer<R> {
+    stream: Stream,
+    reader: R,
+    chunk_size: usize,
+    size: u64,
+    read: u64,
+
+    /// The chunk read ahead of the one being sealed, to know which is last
+    ahead: Option<Zeroizing<Vec<u8>>>,
+}
+
+impl<R: Read> Sealer<R> {
+    pub fn new(stream: Stream, reader: R, chunk_size: u32, size: u64) -> Self {
+        Self {
+            stream,
+            reader,
+            chunk_size: chunk_size as usize,
+            size,
+            read: 0,
+            ahead: None,
+        }
+    }
+
+    /// Read up to a whole chunk, short only at the end
+    fn fill(&mut self) -> io::Result<Zeroizing<Vec<u8>>> {
+        let mut buf = Zeroizing::new(vec![0u8; self.chunk_size]);
+        let mut len = 0;
+        while len < buf.len() {
+            match self.reader.read(&mut buf[len..]) {
+                Ok(0) => break,
+                Ok(n) => len += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        buf.truncate(len);
+        self.read += len as u64;
+        Ok(buf)
+    }
+
+    fn seal_next(&mut self) -> Result<Vec<u8>, Error> {
+        let chunk = match self.ahead.take() {
+            Some(chunk) => chunk,
+            None => self.fill()?,
+        };
+        let next = match chunk.len() == self.chunk_size {
+            true => self.fill()?,
+            false => Zeroizing::new(vec![]),
+        };
+        let last = next.is_empty();
+        if last && self.read != self.size {
+            return Err(Error::Fail(format!(
+                "read {} bytes, expected {}: did the file change?",
+                self.read, self.size
+            )));
+        }
+        self.ahead = Some(next).filter(|_| !last);
+        self.stream.seal(&chunk, last)
+    }
+}
+
+impl<R: Read> Iterator for Sealer<R> {
+    type Item = Result<Vec<u8>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.stream.finished() {
+            return None;
+        }
+        let sealed = self.seal_next();
+        if sealed.is_err() {
+            // Stop rather than seal past an error
+            self.stream.finished = true;
+        }
+        Some(sealed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream() {
+        let key = [7u8; KEY_LEN];
+        let prefix = [3u8; PREFIX_LEN];
+        let data: Vec<u8> = (0..2500u32).map(|i| i as u8).collect();
+        let stream = || Stream::new(&key, &prefix).unwrap();
+
+        let sealed = Sealer::new(stream(), &data[..], 1000, 2500)
+            .collect::<Result<Vec<Vec<u8>>, Error>>()
+            .unwrap();
+        assert_eq!(sealed.len() as u64, chunks(2500, 1000));
+
+        let open = |chunks: &[Vec<u8>]| {
+            let mut s = stream();
+            let mut out = vec![];
+            for (i, chunk) in chunks.iter().enumerate() {
+                out.extend_from_slice(&s.open(chunk, i + 1 == chunks.len())?);
+            }
+            Ok::<_, Error>(out)
+        };
+        assert_eq!(open(&sealed).unwrap(), data);
+
+        // Truncated, reordered and tampered streams do not open
+        assert!(open(&sealed[..2]).is_err());
+        assert!(open(&[&sealed[1], &sealed[0], &sealed[2]].map(Vec::clone))
+            .is_err());
+        let mut tampered = sealed.clone();
+        tampered[1][0] ^= 1;
+        assert!(open(&tampered).is_err());
+        assert!(Stream::new(&key, &[4u8; PREFIX_LEN])
+            .unwrap()
+            .open(&sealed[0], false)
+            .is_err());
+
+        // Nothing still takes a chunk, and sizes must match
+        let empty = Sealer::new(stream(), &[][..], 1000, 0)
+            .collect::<Result<Vec<Vec<u8>>, Error>>()
+            .unwrap();
+        assert_eq!(empty.len(), 1);
+        assert_eq!(open(&empty).unwrap(), Vec::<u8>::new());
+        let exact = Sealer::new(stream(), &data[..2000], 1000, 2000)
+            .collect::<Result<Vec<Vec<u8>>, Error>>()
+            .unwrap();
+        assert_eq!(open(&exact).unwrap(), &data[..2000]);
+        assert!(Sealer::new(stream(), &data[..], 1000, 100).any(|c| c.is_err()));
+    }
+}
@@ -0,0 +1,185 @@
+//! The schemes a secret can be encrypted with. Every `EncSecret` records its
+//! suite, so that old secrets stay readable as the default moves on.
+
+use crate::crypto::NONCE_LEN;
+use crate::Error;
+use aes_gcm::aead::{Aead, NewAead, Payload};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use hkdf::Hkdf;
+use rand::RngCore;
+use rsa::{PaddingScheme, PublicKey as _, RsaPrivateKey, RsaPublicKey};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use x25519_dalek::{EphemeralSecret, PublicKey, StaticSecret};
+use zeroize::Zeroizing;
+
+const AES_KEY_LEN: usize = 32;
+const X25519_LEN: usize = 32;
+const HKDF_INFO: &[u8] = b"spark x25519 hkdf-sha256 aes-256-gcm";
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum CipherSuite {
+    /// RSA-2048 with PKCS#1 v1.5 padding, used before suites existed.
+    /// Vulnerable to padding oracles, so it is only ever decrypted.
+    RsaPkcs1v15,
+
+    /// An AES-256-GCM key wrapped with RSA-2048 OAEP (SHA-256). The fallback
+    /// for server keys made before X25519 support.
+    RsaOaep,
+
+    /// An ephemeral X25519 exchange, HKDF-SHA256 and AES-256-GCM
+    X25519,
+}
+
+/// Encrypt `plaintext` under a fresh AES-256-GCM key, returning the nonce
+/// followed by the ciphertext
+fn aes_seal(
+    key: &[u8],
+    plaintext: &[u8],
+    aad: &[u8],
+) -> Result<Vec<u8>, Error> {
+    let mut nonce = [0u8; NONCE_LEN];
+    rand::thread_rng().fill_bytes(&mut nonce);
+    let ct = Aes256Gcm::new(Key::from_slice(key)).encrypt(
+        Nonce::from_slice(&nonce),
+        Payload {
+            msg: plaintext,
+            aad,
+        },
+    )?;
+    Ok([&nonce[..], &ct[..]].concat())
+}
+
+fn aes_open(
+    key: &[u8],
+    sealed: &[u8],
+    aad: &[u8],
+) -> Result<Zeroizing<Vec<u8>>, Error> {
+    if sealed.len() < NONCE_LEN {
+        return Err(Error::Fail("ciphertext too short".to_string()));
+    }
+    let (nonce, ct) = sealed.split_at(NONCE_LEN);
+    let pt = Aes256Gcm::new(Key::from_slice(key))
+        .decrypt(Nonce::from_slice(nonce), Payload { msg: ct, aad })?;
+    Ok(Zeroizing::new(pt))
+}
+
+/// Derive the AES key shared between an ephemeral and a static X25519 key
+fn x25519_kdf(
+    shared: &[u8],
+    ephemeral: &PublicKey,
+    recipient: &PublicKey,
+) -> Result<Zeroizing<[u8; AES_KEY_LEN]>, Error> {
+    let salt = [&ephemeral.as_bytes()[..], &recipient.as_bytes()[..]].concat();
+    let mut key = Zeroizing::new([0u8; AES_KEY_LEN]);
+    Hkdf::<Sha256>::new(Some(&salt), shared)
+        .expand(HKDF_INFO, &mut key[..])
+        .map_err(|_| Error::Fail("hkdf output too long".to_string()))?;
+    Ok(key)
+}
+
+/// Encrypt to an X25519 key. The output is the ephemeral public key, the
+/// nonce, and the AES-GCM ciphertext.
+pub fn seal_x25519(
+    recipient: &PublicKey,
+    plaintext: &[u8],
+    aad: &[u8],
+) -> Result<Vec<u8>, Error> {
+    let ephemeral = EphemeralSecret::random_from_rng(rand::thread_rng());
+    let ephemeral_pub = PublicKey::from(&ephemeral);
+    let shared = ephemeral.diffie_hellman(recipient);
+    let key = x25519_kdf(shared.as_bytes(), &ephemeral_pub, recipient)?;
+    let sealed = aes_seal(&key[..], plaintext, aad)?;
+    Ok([&ephemeral_pub.as_bytes()[..], &sealed[..]].concat())
+}
+
+pub fn open_x25519(
+    secret: &StaticSecret,
+    ciphertext: &[u8],
+    aad: &[u8],
+) -> Result<Zeroizing<Vec<u8>>, Error> {
+    if ciphertext.len() < X25519_LEN {
+        return Err(Error::Fail("ciphertext too short".to_string()));
+    }
+    let (ephemeral, sealed) = ciphertext.split_at(X25519_LEN);
+    let mut bytes = [0u8; X25519_LEN];
+    bytes.copy_from_slice(ephemeral);
+    let ephemeral = PublicKey::from(bytes);
+    let shared = secret.diffie_hellman(&ephemeral);
+    let key =
+        x25519_kdf(shared.as_bytes(), &ephemeral, &PublicKey::from(secret))?;
+    aes_open(&key[..], sealed, aad)
+}
+
+/// Encrypt under a random AES key wrapped with RSA-OAEP. The output is the
+/// wrapped key, the nonce, and the AES-GCM ciphertext.
+pub fn seal_rsa_oaep(
+    pubkey: &RsaPublicKey,
+    plaintext: &[u8],
+    aad: &[u8],
+) -> Result<Vec<u8>, Error> {
+    let mut key = Zeroizing::new([0u8; AES_KEY_LEN]);
+    rand::thread_rng().fill_bytes(&mut key[..]);
+    let wrapped = pubkey.encrypt(
+        &mut rand::thread_rng(),
+        PaddingScheme::new_oaep::<Sha256>(),
+        &key[..],
+    )?;
+    let sealed = aes_seal(&key[..], plaintext, aad)?;
+    Ok([&wrapped[..], &sealed[..]].concat())
+}
+
+pub fn open_rsa_oaep(
+    privkey: &RsaPrivateKey,
+    ciphertext: &[u8],
+    aad: &[u8],
+) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let len = rsa::PublicKeyParts::size(privkey);
+    if ciphertext.len() < len {
+        return Err(Error::Fail("ciphertext too short".to_string()));
+    }
+    let (wrapped, sealed) = ciphertext.split_at(len);
+    let key = Zeroizing::new(
+        privkey.decrypt(PaddingScheme::new_oaep::<Sha256>(), wrapped)?,
+    );
+    if key.len() != AES_KEY_LEN {
+        return Err(Error::Fail("wrapped key has the wrong length".into()));
+    }
+    aes_open(&key[..], sealed, aad)
+}
+
+pub fn open_rsa_pkcs1v15(
+    privkey: &RsaPrivateKey,
+    ciphertext: &[u8],
+) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let padding = PaddingScheme::new_pkcs1v15_encrypt();
+    Ok(Zeroizing::new(privkey.decrypt(padding, ciphertext)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_x25519() {
+        let secret = StaticSecret::random_from_rng(rand::thread_rng());
+        let ct =
+            seal_x25519(&PublicKey::from(&secret), b"hello", b"aad").unwrap();
+        assert_eq!(&open_x25519(&secret, &ct, b"aad").unwrap()[..], b"hello");
+        assert!(open_x25519(&secret, &ct, b"other").is_err());
+
+        let other = StaticSecret::random_from_rng(rand::thread_rng());
+        assert!(open_x25519(&other, &ct, b"aad").is_err());
+    }
+
+    #[test]
+    fn test_rsa_oaep() {
+        let privkey =
+            RsaPrivateKey::new(&mut rand::thread_rng(), 1024).unwrap();
+        // Longer than RSA alone could encrypt
+        let msg = vec![7u8; 4096];
+        let ct =
+            seal_rsa_oaep(&RsaPublicKey::from(&privkey), &msg, b"").unwrap();
+        assert_eq!(&open_rsa_oaep(&privkey, &ct, b"").unwrap()[..], &msg[..]);
+    }
+}
@@ -1,29 +1,45 @@
-use crate::crypto::types;
+use crate::crypto::passphrase::PassphraseProvider;
+use crate::crypto::policy::{Checked, Policy};
+use crate::crypto::shares;
+use crate::crypto::suite::{self, CipherSuite};
 use crate::crypto::*;
-use crate::{Error, GeneralError, DATA_DIR};
-use aes_gcm::aead::{Aead, NewAead};
-use aes_gcm::{Aes256Gcm, Key, Nonce};
-use argon2::{
-    password_hash::{
-        rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier,
-        SaltString,
-    },
-    Argon2,
-};
-use rsa::{PaddingScheme, PublicKey, RsaPrivateKey, RsaPublicKey};
-use serde::{
-    ser::SerializeSeq, Deserialize, Deserializer, Serialize, Serializer,
-};
+use crate::primitives::payloads::Payload;
+use crate::{Error, DATA_DIR};
+use aes_gcm::aead::Aead;
+use aes_gcm::Nonce;
+use argon2::password_hash::{rand_core::OsRng, SaltString};
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use rand::RngCore;
+use rsa::pkcs8::EncodePublicKey;
+use rsa::{RsaPrivateKey, RsaPublicKey};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt;
 use std::fs;
 use std::fs::File;
-use std::io;
 use std::io::prelude::*;
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use x25519_dalek::{PublicKey, StaticSecret};
+use zeroize::Zeroizing;
+
+/// Encoded `EncServerKey`s start with this and a format version. Keys
+/// without it predate cipher suites.
+const KEY_MAGIC: &[u8] = b"spark-key\0";
+const KEY_VERSION: u8 = 2;
+
+/// Fingerprints are a full SHA-256
+pub const FINGERPRINT_LEN: usize = 32;
+
+/// Public keys are signed with this prefix, so that their signatures cannot
+/// pass for those of secrets
+const PUBLIC_KEY_CONTEXT: &[u8] = b"spark public key\0";
 
 /// Server key information
-/// This structure only lives temporarily, on the client
-#[derive(Debug, Serialize, Deserialize)]
+/// This structure only lives temporarily, on the client. The private key
+/// wipes itself when dropped.
+#[derive(Serialize, Deserialize)]
 pub struct ServerKey {
     /// The core private key used to decrypt secrets
     privkey: RsaPrivateKey,
@@ -33,16 +49,195 @@ pub struct ServerKey {
 
     /// The salt used for all hashing. Must be unique for each key
     salt: [u8; SALT_LEN],
+
+    /// The X25519 key secrets are encrypted to. Keys made before X25519
+    /// support lack one, and fall back to RSA-OAEP.
+    x25519: Option<StaticSecret>,
+
+    /// The ed25519 key that signs every secret written. Keys made before
+    /// signing lack one, and neither sign nor verify.
+    signing: Option<SigningKey>,
+}
+
+/// A `ServerKey` as serialized before signing
+#[derive(Deserialize)]
+struct UnsignedServerKey {
+    privkey: RsaPrivateKey,
+    pubkey: RsaPublicKey,
+    salt: [u8; SALT_LEN],
+    x25519: Option<StaticSecret>,
+}
+
+/// A `ServerKey` as serialized before cipher suites
+#[derive(Deserialize)]
+struct LegacyServerKey {
+    privkey: RsaPrivateKey,
+    pubkey: RsaPublicKey,
+    salt: [u8; SALT_LEN],
+}
+
+/// Names a server key by the SHA-256 of its RSA public key's DER
+/// SubjectPublicKeyInfo, which every key has. It matches
+/// `openssl pkey -pubout -outform DER | sha256sum`.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Fingerprint([u8; FINGERPRINT_LEN]);
+
+impl Fingerprint {
+    /// The fingerprint of an RSA public key
+    pub fn of(key: &RsaPublicKey) -> Result<Self, Error> {
+        let der = key
+            .to_public_key_der()
+            .map_err(|e| Error::Fail(format!("encoding public key: {e}")))?;
+        Ok(Self(Sha256::digest(der.as_ref()).into()))
+    }
+
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        <[u8; FINGERPRINT_LEN]>::try_from(bytes)
+            .map(Self)
+            .map_err(|_| Error::Fail("malformed key fingerprint".to_string()))
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", hex::encode(self.0))
+    }
+}
+
+impl FromStr for Fingerprint {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let bytes = hex::decode(s).map_err(|_| {
+            Error::Fail(format!("malformed key fingerprint {s}"))
+        })?;
+        Self::from_slice(&bytes)
+    }
+}
+
+/// The public half of a `ServerKey`, which is all it takes to encrypt
+/// secrets to it. Keys that sign also sign this, so that a client holding
+/// only the verifying key can trust the rest.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct PublicServerKey {
+    pub rsa: RsaPublicKey,
+    pub x25519: Option<PublicKey>,
+    pub verifying: Option<VerifyingKey>,
+    pub signature: Option<Signature>,
+}
+
+impl PublicServerKey {
+    /// What the signature covers
+    fn signed_bytes(&self) -> Result<Vec<u8>, Error> {
+        let body =
+            bincode::serialize(&(&self.rsa, self.x25519, self.verifying))?;
+        Ok([PUBLIC_KEY_CONTEXT, &body].concat())
+    }
+
+    /// Check the key was signed by its own verifying key. Keys that do not
+    /// sign pass unchecked, so pin `verifying` rather than trusting this
+    /// alone.
+    pub fn verify(&self) -> Result<(), Error> {
+        let key = match &self.verifying {
+            Some(key) => key,
+            None => return Ok(()),
+        };
+        let sig = self.signature.as_ref().ok_or_else(|| {
+            Error::Fail("public key is not signed".to_string())
+        })?;
+        key.verify_strict(&self.signed_bytes()?, sig)
+            .map_err(|_| Error::Fail("public key has a bad signature".into()))
+    }
+
+    pub fn fingerprint(&self) -> Result<Fingerprint, Error> {
+        Fingerprint::of(&self.rsa)
+    }
+
+    /// The suite `encrypt` uses
+    pub fn suite(&self) -> CipherSuite {
+        match self.x25519 {
+            Some(_) => CipherSuite::X25519,
+            None => CipherSuite::RsaOaep,
+        }
+    }
+
+    /// Encrypt a secret without signing it. Keys that sign refuse to decrypt
+    /// it until a holder of the private key accepts it.
+    pub fn encrypt(&self, sec: Secret) -> Result<EncSecret, Error> {
+        let mut enc = seal(&self.rsa, self.x25519.as_ref(), self.suite(), sec)?;
+        enc.key = Some(self.fingerprint()?);
+        Ok(enc)
+    }
+}
+
+/// Encrypt a secret with a particular suite, leaving it unsigned. The
+/// ciphertext is bound to the secret's id.
+fn seal(
+    rsa: &RsaPublicKey,
+    x25519: Option<&PublicKey>,
+    suite: CipherSuite,
+    sec: Secret,
+) -> Result<EncSecret, Error> {
+    sec.check_tag()?;
+    let ser = sec.secret.encode()?;
+    let aad = sec.header.id.bytes();
+    let secret = match (suite, x25519) {
+        (CipherSuite::X25519, Some(x)) => suite::seal_x25519(x, &ser, &aad)?,
+        (CipherSuite::X25519, None) => {
+            return Err(Error::Fail("key has no X25519 part".to_string()))
+        }
+        (CipherSuite::RsaOaep, _) => suite::seal_rsa_oaep(rsa, &ser, &aad)?,
+        (CipherSuite::RsaPkcs1v15, _) => {
+            return Err(Error::Fail(
+                "refusing to encrypt with PKCS#1 v1.5".to_string(),
+            ))
+        }
+    };
+    Ok(EncSecret {
+        secret,
+        header: sec.header,
+        suite,
+        signature: None,
+        key: None,
+    })
 }
 
 /// An encrypted `ServerKey` containing necessary decrypting information.
 /// This is the structure that is sent over the network, and stored on the
 /// server's fs.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct EncServerKey {
     pub server_key: Vec<u8>,
     pub nonce: [u8; NONCE_LEN],
     pub salt: [u8; SALT_LEN],
+
+    /// The suite new secrets are encrypted with under this key
+    pub suite: CipherSuite,
+
+    /// The public half, kept in the clear for clients that only deposit
+    /// secrets. Keys locked before write-only clients lack it.
+    pub public: Option<PublicServerKey>,
+}
+
+/// An `EncServerKey` as serialized in version 1, before public keys
+#[derive(Deserialize)]
+struct EncServerKeyV1 {
+    server_key: Vec<u8>,
+    nonce: [u8; NONCE_LEN],
+    salt: [u8; SALT_LEN],
+    suite: CipherSuite,
+}
+
+/// An `EncServerKey` as serialized before cipher suites
+#[derive(Deserialize)]
+struct LegacyEncServerKey {
+    server_key: Vec<u8>,
+    nonce: [u8; NONCE_LEN],
+    salt: [u8; SALT_LEN],
 }
 
 impl EncServerKey {
@@ -52,8 +247,77 @@ impl EncServerKey {
         let mut file = File::open(path)?;
         let mut buf = Vec::new();
         file.read_to_end(&mut buf)?;
-        bincode::deserialize::<EncServerKey>(&buf)
-            .map_err(|e| Error::Bincode(e))
+        Self::from_bytes(&buf)
+    }
+
+    /// The key's fingerprint, if it carries its public half
+    pub fn fingerprint(&self) -> Result<Option<Fingerprint>, Error> {
+        self.public
+            .as_ref()
+            .map(PublicServerKey::fingerprint)
+            .transpose()
+    }
+
+    /// Where `save` writes this key: `DATA_DIR`, named after its
+    /// fingerprint, or its salt if it has none
+    pub fn path(&self) -> PathBuf {
+        let name = match self.fingerprint() {
+            Ok(Some(fingerprint)) => fingerprint.to_string()[..16].to_string(),
+            _ => hex::encode(self.salt)[..12].to_string(),
+        };
+        Path::new(DATA_DIR).join(format!("{name}.esk"))
+    }
+
+    /// Write this key to `path`, returning it
+    pub fn save(&self) -> Result<String, Error> {
+        let ser = self.to_bytes()?;
+        fs::create_dir_all(DATA_DIR)?;
+        let path = self.path();
+        let mut file = File::create(&path)?;
+        file.write_all(&ser[..])?;
+
+        // Return filename
+        match path.to_str() {
+            Some(p) => Ok(String::from(p)),
+            None => Err(Error::Fail("cannot construct path".to_string())),
+        }
+    }
+
+    /// Serialize for storage, with a format version
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut buf = [KEY_MAGIC, &[KEY_VERSION]].concat();
+        buf.extend(bincode::serialize(self)?);
+        Ok(buf)
+    }
+
+    /// Deserialize a key written by `to_bytes`, or by a version of spark
+    /// that predates cipher suites
+    pub fn from_bytes(buf: &[u8]) -> Result<Self, Error> {
+        match buf.strip_prefix(KEY_MAGIC) {
+            Some([KEY_VERSION, rest @ ..]) => Ok(bincode::deserialize(rest)?),
+            Some([1, rest @ ..]) => {
+                let v1 = bincode::deserialize::<EncServerKeyV1>(rest)?;
+                Ok(Self {
+                    server_key: v1.server_key,
+                    nonce: v1.nonce,
+                    salt: v1.salt,
+                    suite: v1.suite,
+                    public: None,
+                })
+            }
+            Some(_) => Err(Error::Fail("unknown server key version".into())),
+            None => {
+                let legacy = bincode::deserialize::<LegacyEncServerKey>(buf)?;
+                Ok(Self {
+                    server_key: legacy.server_key,
+                    nonce: legacy.nonce,
+                    salt: legacy.salt,
+                    // Legacy keys have no X25519 part
+                    suite: CipherSuite::RsaOaep,
+                    public: None,
+                })
+            }
+        }
     }
 }
 
@@ -66,6 +330,16 @@ impl EncServerKey {
  * To decrypt: server will also send file, and will use Encryptor to decrypt
  */
 
+/// A fresh salt for a new key
+fn new_salt() -> [u8; SALT_LEN] {
+    // TODO Specify len explicitly
+    let raw_salt = SaltString::generate(&mut OsRng);
+    let raw_salt = raw_salt.as_bytes();
+    let mut salt = [0u8; SALT_LEN];
+    salt.copy_from_slice(&raw_salt[..SALT_LEN]);
+    salt
+}
+
 impl ServerKey {
     /// Initialize a new server key
     pub fn new() -> Self {
@@ -75,128 +349,322 @@ impl ServerKey {
             .expect("failed to generate a key");
         let pubkey = RsaPublicKey::from(&privkey);
 
-        // Saltgen TODO Specify len explicitly
-        let raw_salt = SaltString::generate(&mut OsRng);
-        let raw_salt = raw_salt.as_bytes();
-        let mut salt = [0u8; SALT_LEN];
-        for i in 0..SALT_LEN {
-            salt[i] = raw_salt[i];
-        }
-
         Self {
             privkey,
             pubkey,
-            salt,
+            salt: new_salt(),
+            x25519: Some(StaticSecret::random_from_rng(&mut rng)),
+            signing: Some(SigningKey::generate(&mut rng)),
+        }
+    }
+
+    /// Build a key around an RSA private key from `private_key_pem` or
+    /// another tool. It has no X25519 or signing half, so like keys that
+    /// predate those it encrypts with RSA-OAEP and does not sign.
+    pub fn from_private_key_pem(
+        pem: &str,
+        passphrase: &dyn PassphraseProvider,
+    ) -> Result<Self, Error> {
+        let privkey =
+            pem::private_from_pem(pem, &passphrase.passphrase(false)?)?;
+        Ok(Self {
+            pubkey: RsaPublicKey::from(&privkey),
+            privkey,
+            salt: new_salt(),
+            x25519: None,
+            signing: None,
+        })
+    }
+
+    /// The RSA public key as PEM, which is safe to publish
+    pub fn public_key_pem(&self) -> Result<String, Error> {
+        pem::public_to_pem(&self.pubkey)
+    }
+
+    /// The RSA private key as PKCS#8 PEM, encrypted with its own passphrase
+    pub fn private_key_pem(
+        &self,
+        passphrase: &dyn PassphraseProvider,
+    ) -> Result<Zeroizing<String>, Error> {
+        pem::private_to_pem(&self.privkey, &passphrase.passphrase(true)?)
+    }
+
+    /// The key that verifies secrets written with this key, if it signs
+    pub fn verifying_key(&self) -> Option<VerifyingKey> {
+        self.signing.as_ref().map(SigningKey::verifying_key)
+    }
+
+    pub fn fingerprint(&self) -> Result<Fingerprint, Error> {
+        Fingerprint::of(&self.pubkey)
+    }
+
+    /// The public half of this key, signed if this key signs
+    pub fn public(&self) -> Result<PublicServerKey, Error> {
+        let mut public = PublicServerKey {
+            rsa: self.pubkey.clone(),
+            x25519: self.x25519.as_ref().map(PublicKey::from),
+            verifying: self.verifying_key(),
+            signature: None,
+        };
+        if let Some(signing) = &self.signing {
+            public.signature = Some(signing.sign(&public.signed_bytes()?));
+        }
+        Ok(public)
+    }
+
+    /// Decrypt a secret deposited with the public key alone, so that it can
+    /// be looked over before it is accepted. Anyone allowed to put secrets
+    /// could have written it.
+    pub fn open_deposit(&self, sec: EncSecret) -> Result<Secret, Error> {
+        if sec.signature.is_some() {
+            return Err(Error::Fail(format!(
+                "secret {} is already signed",
+                sec.header.label
+            )));
+        }
+        self.open(sec)
+    }
+
+    /// Sign a deposit, once its content is known to be what was expected
+    pub fn accept(&self, sec: Secret) -> Result<EncSecret, Error> {
+        self.encrypt(sec)
+    }
+
+    /// Check a secret was signed by this key. Keys that sign accept no
+    /// unsigned secrets, since every secret under them was written signed
+    /// or accepted.
+    pub fn verify(&self, sec: &EncSecret) -> Result<(), Error> {
+        let key = match self.verifying_key() {
+            Some(key) => key,
+            None => return Ok(()),
+        };
+        let sig = sec.signature.as_ref().ok_or_else(|| {
+            Error::Fail(format!(
+                "secret {} is not signed. If it was deposited, accept it \
+                 first.",
+                sec.header.label
+            ))
+        })?;
+        key.verify_strict(&sec.signed_bytes()?, sig).map_err(|_| {
+            Error::Fail(format!(
+                "secret {} has a bad signature",
+                sec.header.label
+            ))
+        })
+    }
+
+    /// The suite `Encryptor::encrypt` uses with this key
+    pub fn suite(&self) -> CipherSuite {
+        match self.x25519 {
+            Some(_) => CipherSuite::X25519,
+            None => CipherSuite::RsaOaep,
         }
     }
 
     /// Convert a `ServerKey` into an `EncServerKey`
-    fn lock(self) -> Result<EncServerKey, Error> {
+    pub fn lock(
+        self,
+        passphrase: &dyn PassphraseProvider,
+    ) -> Result<EncServerKey, Error> {
         // Serialize
-        let ser = bincode::serialize(&self)?;
+        let ser = Zeroizing::new(bincode::serialize(&self)?);
 
-        // TODO make idiomatic
-        let mut ser_nonce = [0u8; NONCE_LEN];
-        for i in 0..NONCE_LEN {
-            ser_nonce[i] = self.salt[i];
-        }
-        let nonce = Nonce::from_slice(&self.salt[0..NONCE_LEN]);
+        // A fresh nonce every time, since the salt, and so the derived key
+        // for the same passphrase, stays with the key across re-locks
+        let mut nonce = [0u8; NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut nonce);
 
         // Encrypt
-        let cipher = derive_key(self.salt, true)?;
+        let cipher = derive_key(self.salt, &passphrase.passphrase(true)?)?;
         Ok(EncServerKey {
-            server_key: cipher.encrypt(nonce, &ser[..])?,
-            nonce: ser_nonce,
+            server_key: cipher.encrypt(Nonce::from_slice(&nonce), &ser[..])?,
+            nonce,
             salt: self.salt,
+            suite: self.suite(),
+            public: Some(self.public()?),
         })
     }
 
     /// Convert an `EncServerKey` into a `ServerKey`
-    pub fn unlock(enc: EncServerKey) -> Result<Self, Error> {
+    pub fn unlock(
+        enc: EncServerKey,
+        passphrase: &dyn PassphraseProvider,
+    ) -> Result<Self, Error> {
         // Decrypt the bytes
-        let cipher = derive_key(enc.salt, false)?;
+        let cipher = derive_key(enc.salt, &passphrase.passphrase(false)?)?;
         let nonce = Nonce::from_slice(&enc.nonce[..]);
-        let decrypted = cipher.decrypt(nonce, &enc.server_key[..])?;
+        let decrypted =
+            Zeroizing::new(cipher.decrypt(nonce, &enc.server_key[..])?);
+        Self::from_plaintext(&decrypted)
+    }
 
-        // Deserialize
-        Ok(bincode::deserialize::<Self>(&decrypted[..])?)
+    /// Split this key into `count` shares, any `threshold` of which
+    /// `recover` it without the passphrase
+    pub fn split(
+        &self,
+        threshold: u8,
+        count: u8,
+    ) -> Result<Vec<Zeroizing<String>>, Error> {
+        let ser = Zeroizing::new(bincode::serialize(self)?);
+        shares::split(&ser, threshold, count)
     }
 
-    /// Lock and write this key to the disk
-    // TODO Make return path
-    pub fn write_key(self) -> Result<String, Error> {
-        // Lock and serialize
-        let salt: [u8; SALT_LEN] = self.salt;
-        let locked = self.lock()?;
-        let ser = bincode::serialize(&locked)?;
-
-        // Set destination
-        let filename = format!("{}{}", &hex::encode(salt)[0..12], ".esk");
-        fs::create_dir_all(DATA_DIR)?;
-        let path = Path::new(DATA_DIR).join(filename);
-        let mut file = File::create(&path)?;
-        file.write_all(&ser[..])?;
+    /// Rebuild a key from shares made by `split`
+    pub fn recover<S: AsRef<str>>(shares: &[S]) -> Result<Self, Error> {
+        Self::from_plaintext(&shares::combine(shares)?)
+    }
 
-        // Return filename
-        match path.to_str() {
-            Some(p) => Ok(String::from(p)),
-            None => Err(Error::Fail("cannot construct path".to_string())),
+    /// Deserialize an unlocked key. Older keys end before the fields added
+    /// since, so they fail to parse as current ones.
+    fn from_plaintext(decrypted: &[u8]) -> Result<Self, Error> {
+        if let Ok(key) = bincode::deserialize::<Self>(decrypted) {
+            return Ok(key);
+        }
+        if let Ok(old) = bincode::deserialize::<UnsignedServerKey>(decrypted) {
+            return Ok(Self {
+                privkey: old.privkey,
+                pubkey: old.pubkey,
+                salt: old.salt,
+                x25519: old.x25519,
+                signing: None,
+            });
+        }
+        let legacy = bincode::deserialize::<LegacyServerKey>(decrypted)?;
+        Ok(Self {
+            privkey: legacy.privkey,
+            pubkey: legacy.pubkey,
+            salt: legacy.salt,
+            x25519: None,
+            signing: None,
+        })
+    }
+
+    /// Encrypt and sign a secret with a particular suite. The ciphertext is
+    /// bound to the secret's id.
+    pub fn encrypt_with(
+        &self,
+        suite: CipherSuite,
+        sec: Secret,
+    ) -> Result<EncSecret, Error> {
+        let x25519 = self.x25519.as_ref().map(PublicKey::from);
+        let mut enc = seal(&self.pubkey, x25519.as_ref(), suite, sec)?;
+        enc.key = Some(self.fingerprint()?);
+        if let Some(signing) = &self.signing {
+            enc.signature = Some(signing.sign(&enc.signed_bytes()?));
         }
+        Ok(enc)
+    }
+
+    /// Decrypt a secret without checking its signature
+    fn open(&self, sec: EncSecret) -> Result<Secret, Error> {
+        let fingerprint = self.fingerprint()?;
+        if let Some(key) = sec.key.filter(|k| *k != fingerprint) {
+            return Err(Error::Fail(format!(
+                "secret {} belongs to key {key}, not {fingerprint}",
+                sec.header.label
+            )));
+        }
+
+        // Decrypt with whichever suite the secret was written with
+        let aad = sec.header.id.bytes();
+        let dec = match (sec.suite, &self.x25519) {
+            (CipherSuite::X25519, Some(x)) => {
+                suite::open_x25519(x, &sec.secret, &aad)?
+            }
+            (CipherSuite::X25519, None) => {
+                return Err(Error::Fail("key has no X25519 part".to_string()))
+            }
+            (CipherSuite::RsaOaep, _) => {
+                suite::open_rsa_oaep(&self.privkey, &sec.secret, &aad)?
+            }
+            (CipherSuite::RsaPkcs1v15, _) => {
+                suite::open_rsa_pkcs1v15(&self.privkey, &sec.secret)?
+            }
+        };
+        Ok(Secret {
+            secret: Payload::decode(&dec[..])?,
+            header: sec.header,
+        })
+    }
+
+    /// Lock and write this key to the disk, once its passphrase meets
+    /// `policy`
+    // TODO Make return path
+    pub fn write_key(
+        self,
+        passphrase: &dyn PassphraseProvider,
+        policy: &Policy,
+    ) -> Result<String, Error> {
+        self.lock(&Checked {
+            provider: passphrase,
+            policy,
+        })?
+        .save()
     }
 
     /// Read and decrypt a key from disk
-    pub fn read_key(path: &Path) -> Result<Self, Error> {
+    pub fn read_key(
+        path: &Path,
+        passphrase: &dyn PassphraseProvider,
+    ) -> Result<Self, Error> {
         // Read file, deserialize, decrypt
         let mut file = File::open(path)?;
         let mut buf = Vec::new();
         file.read_to_end(&mut buf)?;
-        let key = bincode::deserialize::<EncServerKey>(&buf)?;
+        let key = EncServerKey::from_bytes(&buf)?;
 
         // Decrypt
-        Self::unlock(key)
+        Self::unlock(key, passphrase)
+    }
+}
+
+impl fmt::Debug for ServerKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ServerKey")
+            .field("privkey", &"[redacted]")
+            .field("pubkey", &self.pubkey)
+            .field("salt", &hex::encode(self.salt))
+            .field("suite", &self.suite())
+            .field("verifying_key", &self.verifying_key().map(hex::encode))
+            .finish()
+    }
+}
+
+impl Default for ServerKey {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 // TODO: rename ServerKey to Key
 impl Encryptor for ServerKey {
-    fn encrypt(&self, sec: Secret) -> Result<EncSecret, Error> {
-        // Serialize and encrypt
-        let ser = bincode::serialize(&sec.secret)?;
-        let mut rng = rand::thread_rng();
-        let padding = PaddingScheme::new_pkcs1v15_encrypt();
+    fn fingerprint(&self) -> Result<Fingerprint, Error> {
+        ServerKey::fingerprint(self)
+    }
 
-        Ok(EncSecret {
-            secret: self.pubkey.encrypt(&mut rng, padding, &ser[..])?,
-            header: sec.header,
-        })
+    fn encrypt(&self, sec: Secret) -> Result<EncSecret, Error> {
+        self.encrypt_with(self.suite(), sec)
     }
 
     fn decrypt(&self, sec: EncSecret) -> Result<Secret, Error> {
-        // Decrypt
-        let mut rng = rand::thread_rng(); // TODO: necessary??
-        let padding = PaddingScheme::new_pkcs1v15_encrypt();
-        let dec = self.privkey.decrypt(padding, &sec.secret[..])?;
-        // same thinking i did when i was in gleason, idk where that code went
-        // TODO: when i wake up: test Encryptor::encrypt/decrypt in isolation
-
-        println!("this is okay");
-        println!("dec = {dec:?}");
-
-        // Deserialize
-        Ok(Secret {
-            secret: bincode::deserialize(&dec[..])?,
-            header: sec.header,
-        })
+        // Refuse secrets this key did not write
+        self.verify(&sec)?;
+        self.open(sec)
     }
 }
 
+#[cfg(test)]
 mod tests {
     use super::*;
+    use crate::crypto::passphrase::Env;
+    use crate::primitives::payloads::{GenericPayload, Payload};
+    use crate::primitives::secret::{Header, Scope, Tag};
 
     #[test]
     fn test_serverkey() {
         let sk = ServerKey::new();
         println!("generated server key: {:?}", sk);
+        assert!(format!("{sk:?}").contains("privkey: \"[redacted]\""));
     }
 
     #[test]
@@ -204,6 +672,242 @@ mod tests {
         let sk = ServerKey::new();
         println!("server key: {:?}", sk);
 
-        let locked = sk.lock().unwrap();
+        std::env::set_var("SPARK_TEST_LOCK", "correct horse");
+        let passphrase = Env("SPARK_TEST_LOCK".to_string());
+        let locked = sk.lock(&passphrase).unwrap();
+        ServerKey::unlock(locked.clone(), &passphrase).unwrap();
+
+        std::env::set_var("SPARK_TEST_LOCK", "battery staple");
+        assert!(ServerKey::unlock(locked, &passphrase).is_err());
+    }
+
+    fn secret(msg: &[u8]) -> Secret {
+        Secret {
+            secret: Payload::Generic(GenericPayload::new(msg.to_vec())),
+            header: Header::new("suite", None, None, 0, Scope::Public).unwrap(),
+        }
+    }
+
+    fn message(sec: Secret) -> Vec<u8> {
+        match sec.secret {
+            Payload::Generic(p) => p.secret().to_vec(),
+            _ => panic!("wrong payload"),
+        }
+    }
+
+    #[test]
+    fn test_suites() {
+        let mut sk = ServerKey::new();
+        // Signing is covered by test_signatures
+        sk.signing = None;
+        let enc = sk.encrypt(secret(b"new")).unwrap();
+        assert_eq!(enc.suite, CipherSuite::X25519);
+        assert_eq!(message(sk.decrypt(enc).unwrap()), b"new");
+
+        let enc = sk.encrypt_with(CipherSuite::RsaOaep, secret(b"oaep"));
+        assert_eq!(message(sk.decrypt(enc.unwrap()).unwrap()), b"oaep");
+        assert!(sk
+            .encrypt_with(CipherSuite::RsaPkcs1v15, secret(b"old"))
+            .is_err());
+
+        // Secrets written before suites are still readable
+        let sec = secret(b"old");
+        let ser = sec.secret.encode().unwrap();
+        let padding = rsa::PaddingScheme::new_pkcs1v15_encrypt();
+        let legacy = EncSecret {
+            secret: rsa::PublicKey::encrypt(
+                &sk.pubkey,
+                &mut rand::thread_rng(),
+                padding,
+                &ser,
+            )
+            .unwrap(),
+            header: sec.header.clone(),
+            suite: CipherSuite::RsaPkcs1v15,
+            signature: None,
+            key: None,
+        };
+        assert_eq!(message(sk.decrypt(legacy).unwrap()), b"old");
+
+        // Ciphertexts are bound to their secret's id
+        let mut enc = sk.encrypt(secret(b"new")).unwrap();
+        enc.header =
+            Header::new("other", None, None, 0, Scope::Public).unwrap();
+        assert!(sk.decrypt(enc).is_err());
+    }
+
+    #[test]
+    fn test_split() {
+        let sk = ServerKey::new();
+        let shares = sk.split(2, 3).unwrap();
+        let recovered = ServerKey::recover(&shares[1..]).unwrap();
+        assert_eq!(recovered.salt, sk.salt);
+        assert_eq!(recovered.verifying_key(), sk.verifying_key());
+
+        // The recovered key reads what the original wrote
+        let enc = sk.encrypt(secret(b"shared")).unwrap();
+        assert_eq!(message(recovered.decrypt(enc).unwrap()), b"shared");
+        assert!(ServerKey::recover(&shares[..1]).is_err());
+
+        // Locking it again, as recovery does, never reuses a nonce
+        std::env::set_var("SPARK_TEST_SPLIT", "correct horse");
+        let passphrase = Env("SPARK_TEST_SPLIT".to_string());
+        let first = sk.lock(&passphrase).unwrap();
+        let again = recovered.lock(&passphrase).unwrap();
+        assert_eq!(first.salt, again.salt);
+        assert_ne!(first.nonce, again.nonce);
+    }
+
+    #[test]
+    fn test_signatures() {
+        let sk = ServerKey::new();
+        let enc = sk.encrypt(secret(b"signed")).unwrap();
+        assert!(enc.signature.is_some());
+        sk.verify(&enc).unwrap();
+
+        // Tampering with any signed part is caught before decrypting
+        let mut bad = sk.encrypt(secret(b"signed")).unwrap();
+        bad.header.label = "renamed".to_string();
+        assert!(sk.decrypt(bad).is_err());
+        let mut bad = sk.encrypt(secret(b"signed")).unwrap();
+        bad.signature = None;
+        assert!(sk.decrypt(bad).is_err());
+
+        // A secret encrypted to this key by anyone else is refused
+        let mut forger = ServerKey::new();
+        forger.x25519 = sk.x25519.clone();
+        let forged = forger.encrypt(secret(b"forged")).unwrap();
+        assert!(sk.decrypt(forged).is_err());
+        assert_eq!(message(sk.decrypt(enc).unwrap()), b"signed");
+    }
+
+    #[test]
+    fn test_legacy_key() {
+        std::env::set_var("SPARK_TEST_LEGACY", "correct horse");
+        let passphrase = Env("SPARK_TEST_LEGACY".to_string());
+
+        // Lock a key the way versions without X25519 did
+        let sk = ServerKey::new();
+        let ser =
+            bincode::serialize(&(&sk.privkey, &sk.pubkey, sk.salt)).unwrap();
+        let cipher = derive_key(sk.salt, "correct horse").unwrap();
+        let nonce = Nonce::from_slice(&sk.salt[..NONCE_LEN]);
+        let mut legacy_nonce = [0u8; NONCE_LEN];
+        legacy_nonce.copy_from_slice(&sk.salt[..NONCE_LEN]);
+        let legacy = bincode::serialize(&(
+            cipher.encrypt(nonce, &ser[..]).unwrap(),
+            legacy_nonce,
+            sk.salt,
+        ))
+        .unwrap();
+
+        let enc = EncServerKey::from_bytes(&legacy).unwrap();
+        assert_eq!(enc.suite, CipherSuite::RsaOaep);
+
+        let unlocked = ServerKey::unlock(enc, &passphrase).unwrap();
+        assert_eq!(unlocked.suite(), CipherSuite::RsaOaep);
+        let enc = unlocked.encrypt(secret(b"fallback")).unwrap();
+        assert_eq!(enc.suite, CipherSuite::RsaOaep);
+        assert_eq!(message(unlocked.decrypt(enc).unwrap()), b"fallback");
+
+        // Current keys round trip through their encoding
+        let locked = ServerKey::new().lock(&passphrase).unwrap();
+        let bytes = locked.to_bytes().unwrap();
+        assert_eq!(EncServerKey::from_bytes(&bytes).unwrap(), locked);
+    }
+
+    #[test]
+    fn test_public() {
+        std::env::set_var("SPARK_TEST_PUBLIC", "correct horse");
+        let passphrase = Env("SPARK_TEST_PUBLIC".to_string());
+
+        let sk = ServerKey::new();
+        let public = sk.public().unwrap();
+        public.verify().unwrap();
+        assert_eq!(public.verifying, sk.verifying_key());
+        let mut forged = public.clone();
+        forged.x25519 = ServerKey::new().public().unwrap().x25519;
+        assert!(forged.verify().is_err());
+
+        // Deposits are unsigned until accepted
+        let deposit = public.encrypt(secret(b"deposit")).unwrap();
+        assert!(deposit.signature.is_none());
+        assert!(sk.verify(&deposit).is_err());
+        let opened = sk.open_deposit(deposit).unwrap();
+        let accepted = sk.accept(opened).unwrap();
+        let signed = sk.encrypt(secret(b"signed")).unwrap();
+        assert!(sk.open_deposit(signed).is_err());
+        let mut tagged = secret(b"not a key");
+        tagged.header.tag = Some(Tag::SshKey);
+        assert!(public.encrypt(tagged).is_err());
+        assert_eq!(message(sk.decrypt(accepted).unwrap()), b"deposit");
+
+        // The public half is kept with the locked key, but not by version 1
+        let locked = sk.lock(&passphrase).unwrap();
+        assert_eq!(locked.public, Some(public));
+        let mut v1 = [KEY_MAGIC, &[1]].concat();
+        v1.extend(
+            bincode::serialize(&(
+                &locked.server_key,
+                locked.nonce,
+                locked.salt,
+                locked.suite,
+            ))
+            .unwrap(),
+        );
+        let old = EncServerKey::from_bytes(&v1).unwrap();
+        assert!(old.public.is_none());
+        ServerKey::unlock(old, &passphrase).unwrap();
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        std::env::set_var("SPARK_TEST_FINGERPRINT", "correct horse");
+        let passphrase = Env("SPARK_TEST_FINGERPRINT".to_string());
+
+        let sk = ServerKey::new();
+        let fingerprint = sk.fingerprint().unwrap();
+        assert_eq!(sk.public().unwrap().fingerprint().unwrap(), fingerprint);
+        assert_eq!(
+            fingerprint.to_string().parse::<Fingerprint>().unwrap(),
+            fingerprint
+        );
+        assert!("00ff".parse::<Fingerprint>().is_err());
+
+        // Secrets carry their key's fingerprint, under the signature
+        let enc = sk.encrypt(secret(b"tagged")).unwrap();
+        assert_eq!(enc.key, Some(fingerprint));
+        let other = ServerKey::new();
+        let deposit = sk.public().unwrap().encrypt(secret(b"tagged")).unwrap();
+        let err = other.open_deposit(deposit).unwrap_err();
+        assert!(err.to_string().contains("belongs to key"));
+        let mut enc = sk.encrypt(secret(b"tagged")).unwrap();
+        enc.key = Some(other.fingerprint().unwrap());
+        assert!(sk.verify(&enc).is_err());
+
+        let locked = sk.lock(&passphrase).unwrap();
+        assert_eq!(locked.fingerprint().unwrap(), Some(fingerprint));
+        assert!(locked
+            .path()
+            .ends_with(format!("{}.esk", &fingerprint.to_string()[..16])));
+    }
+
+    #[test]
+    fn test_pem_key() {
+        std::env::set_var("SPARK_TEST_PEM", "battery staple");
+        let passphrase = Env("SPARK_TEST_PEM".to_string());
+
+        let sk = ServerKey::new();
+        assert!(sk.public_key_pem().unwrap().contains("PUBLIC KEY"));
+        let pem = sk.private_key_pem(&passphrase).unwrap();
+
+        // Only the RSA half comes back, so the key falls back to OAEP
+        let imported =
+            ServerKey::from_private_key_pem(&pem, &passphrase).unwrap();
+        assert_eq!(imported.privkey, sk.privkey);
+        assert_eq!(imported.suite(), CipherSuite::RsaOaep);
+        assert!(imported.verifying_key().is_none());
+        let enc = imported.encrypt(secret(b"imported")).unwrap();
+        assert_eq!(message(imported.decrypt(enc).unwrap()), b"imported");
     }
 }
@@ -0,0 +1,277 @@
+use clap::{Parser, Subcommand};
+use spark::crypto::policy::PolicyArgs;
+use spark::crypto::types::{EncServerKey, ServerKey};
+use spark::crypto::{backup, passphrase, pem};
+use std::error::Error;
+use std::fs;
+use std::io::{self, BufRead, Read, Write};
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::PathBuf;
+use zeroize::Zeroizing;
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    #[command(
+        about = "Split a key into shares, a threshold of which recover it"
+    )]
+    Split {
+        key: PathBuf,
+        #[arg(long, help = "How many shares it takes to recover the key")]
+        threshold: u8,
+        #[arg(long, help = "How many shares to make")]
+        shares: u8,
+        #[arg(
+            long,
+            help = "Write each share to its own file in this directory \
+                    instead of printing them"
+        )]
+        out: Option<PathBuf>,
+    },
+    #[command(about = "Rebuild a key from its shares and lock it with a new \
+                 passphrase, replacing the key file")]
+    Recover {
+        #[arg(help = "Files of one share each, or read them from stdin, one \
+                      per line")]
+        shares: Vec<PathBuf>,
+        #[arg(
+            long,
+            default_value = "tty",
+            help = "Where to read the new passphrase from"
+        )]
+        new_passphrase_source: String,
+        #[command(flatten)]
+        policy: PolicyArgs,
+    },
+    #[command(about = "Print a key, still locked, in a form fit for paper")]
+    Export {
+        key: PathBuf,
+        #[arg(long, help = "Print numbered lines of words instead of armor")]
+        words: bool,
+    },
+    #[command(
+        about = "Check an exported key and write it back out as a key file"
+    )]
+    Import {
+        #[arg(help = "The exported key, or read it from stdin")]
+        path: Option<PathBuf>,
+        #[arg(long, help = "Replace an existing key file")]
+        force: bool,
+    },
+    #[command(about = "Print a key's fingerprint, which names it to servers \
+                 and clients")]
+    Fingerprint { key: PathBuf },
+    #[command(about = "Print a key's RSA public key as PEM")]
+    Public {
+        key: PathBuf,
+        #[arg(
+            long,
+            help = "Print the ed25519 key that write-only clients pin \
+                    instead"
+        )]
+        verifying: bool,
+    },
+    #[command(about = "Print a key's RSA private key as encrypted PKCS#8 PEM")]
+    ExportPrivate {
+        key: PathBuf,
+        #[arg(
+            long,
+            default_value = "tty",
+            help = "Where to read the passphrase for the PEM from"
+        )]
+        pem_passphrase_source: String,
+    },
+    #[command(about = "Make a key file from an encrypted PKCS#8 PEM private \
+                 key. The key encrypts with RSA-OAEP and does not sign.")]
+    ImportPrivate {
+        #[arg(help = "The PEM file, or read it from stdin")]
+        path: Option<PathBuf>,
+        #[arg(
+            long,
+            default_value = "tty",
+            help = "Where to read the passphrase for the PEM from"
+        )]
+        pem_passphrase_source: String,
+        #[arg(
+            long,
+            default_value = "tty",
+            help = "Where to read the new key's passphrase from"
+        )]
+        new_passphrase_source: String,
+        #[command(flatten)]
+        policy: PolicyArgs,
+    },
+}
+
+#[derive(Parser, Debug)]
+#[command(author, version, about = "Manage server keys offline")]
+struct Args {
+    #[arg(
+        long,
+        default_value = "tty",
+        help = "Where to read the key's passphrase from: tty, env:VAR, fd:N, \
+                file:PATH or cmd:PROGRAM"
+    )]
+    passphrase_source: String,
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// Read a file, or stdin if there is none
+fn read_input(path: Option<PathBuf>) -> Result<String, Box<dyn Error>> {
+    match path {
+        Some(path) => Ok(fs::read_to_string(path)?),
+        None => {
+            let mut text = String::new();
+            io::stdin().read_to_string(&mut text)?;
+            Ok(text)
+        }
+    }
+}
+
+/// Read one share per file, or per line of stdin if there are no files
+fn read_shares(
+    paths: &[PathBuf],
+) -> Result<Vec<Zeroizing<String>>, Box<dyn Error>> {
+    if !paths.is_empty() {
+        return paths
+            .iter()
+            .map(|p| Ok(Zeroizing::new(fs::read_to_string(p)?)))
+            .collect();
+    }
+    let mut shares = Vec::new();
+    for line in io::stdin().lock().lines() {
+        let line = Zeroizing::new(line?);
+        if !line.trim().is_empty() {
+            shares.push(line);
+        }
+    }
+    Ok(shares)
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+
+    env_logger::init();
+
+    match args.command {
+        Command::Split {
+            key,
+            threshold,
+            shares,
+            out,
+        } => {
+            let source = passphrase::parse(&args.passphrase_source)?;
+            let key = ServerKey::read_key(&key, source.as_ref())?;
+            let shares = key.split(threshold, shares)?;
+            match out {
+                Some(dir) => {
+                    fs::create_dir_all(&dir)?;
+                    for (i, share) in shares.iter().enumerate() {
+                        let path = dir.join(format!("share-{}.txt", i + 1));
+                        let mut file = fs::OpenOptions::new()
+                            .write(true)
+                            .create_new(true)
+                            .mode(0o600)
+                            .open(&path)?;
+                        file.write_all(share.as_bytes())?;
+                        file.write_all(b"\n")?;
+                        println!("{}", path.display());
+                    }
+                }
+                None => shares.iter().for_each(|s| println!("{}", **s)),
+            }
+        }
+        Command::Recover {
+            shares,
+            new_passphrase_source,
+            policy,
+        } => {
+            let policy = policy.policy()?;
+            let key = ServerKey::recover(&read_shares(&shares)?)?;
+            let source = passphrase::parse(&new_passphrase_source)?;
+            let path = key.write_key(&*source, &policy)?;
+            println!("recovered key written to {path}");
+        }
+        Command::Export { key, words } => {
+            let key = EncServerKey::load(key)?.to_bytes()?;
+            match words {
+                true => print!("{}", backup::to_words(&key)),
+                false => print!("{}", backup::armor(&key)),
+            }
+        }
+        Command::Import { path, force } => {
+            let text = read_input(path)?;
+            // Armor is recognised by its BEGIN line, anything else is words
+            let bytes = match text.contains("-----BEGIN") {
+                true => backup::dearmor(&text)?,
+                false => backup::from_words(&text)?,
+            };
+            let key = EncServerKey::from_bytes(&bytes)?;
+            if key.path().exists() && !force {
+                return Err(format!(
+                    "{} exists, pass --force to replace it",
+                    key.path().display()
+                )
+                .into());
+            }
+            println!("imported key written to {}", key.save()?);
+        }
+        Command::Fingerprint { key } => {
+            let enc = EncServerKey::load(key)?;
+            // Keys locked before public keys must be unlocked to tell
+            let fingerprint = match enc.fingerprint()? {
+                Some(fingerprint) => fingerprint,
+                None => {
+                    let source = passphrase::parse(&args.passphrase_source)?;
+                    ServerKey::unlock(enc, source.as_ref())?.fingerprint()?
+                }
+            };
+            println!("{fingerprint}");
+        }
+        Command::Public { key, verifying } => {
+            let enc = EncServerKey::load(key)?;
+            // The public half is kept outside the lock, except in keys
+            // locked before it was
+            let public = match enc.public {
+                Some(public) => public,
+                None => {
+                    let source = passphrase::parse(&args.passphrase_source)?;
+                    ServerKey::unlock(enc, source.as_ref())?.public()?
+                }
+            };
+            match verifying {
+                true => println!(
+                    "{}",
+                    hex::encode(
+                        public.verifying.ok_or("the key does not sign")?
+                    )
+                ),
+                false => print!("{}", pem::public_to_pem(&public.rsa)?),
+            }
+        }
+        Command::ExportPrivate {
+            key,
+            pem_passphrase_source,
+        } => {
+            let source = passphrase::parse(&args.passphrase_source)?;
+            let key = ServerKey::read_key(&key, source.as_ref())?;
+            let source = passphrase::parse(&pem_passphrase_source)?;
+            print!("{}", *key.private_key_pem(source.as_ref())?);
+        }
+        Command::ImportPrivate {
+            path,
+            pem_passphrase_source,
+            new_passphrase_source,
+            policy,
+        } => {
+            let policy = policy.policy()?;
+            let pem = Zeroizing::new(read_input(path)?);
+            let source = passphrase::parse(&pem_passphrase_source)?;
+            let key = ServerKey::from_private_key_pem(&pem, source.as_ref())?;
+            let source = passphrase::parse(&new_passphrase_source)?;
+            let path = key.write_key(&*source, &policy)?;
+            println!("imported key written to {path}");
+        }
+    }
+    Ok(())
+}
@@ -1,6 +1,7 @@
 pub mod core;
 pub mod crypto;
 pub mod primitives;
+#[allow(unused_parens, clippy::all)]
 pub mod protocol_capnp;
 
 use argon2::password_hash::errors::Error as HashError;
@@ -10,8 +11,8 @@ use std::str;
 use std::time::SystemTimeError;
 use std::{fmt, io};
 
-pub const DATA_DIR: &'static str = "./data/";
-pub const BOOTSTRAP_FILE: &'static str = "./Bootstrap.toml";
+pub const DATA_DIR: &str = "./data/";
+pub const BOOTSTRAP_FILE: &str = "./Bootstrap.toml";
 
 #[derive(Debug)]
 pub struct GeneralError {
@@ -49,12 +50,14 @@ pub enum Error {
     Hashing(HashError),
     SystemTime(SystemTimeError),
     Sled(sled::Error),
+    Capnp(capnp::Error),
+    Denied(String),
     Fail(String),
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Error: {:?}", self.to_string())
+        write!(f, "Error: {:?}", self)
     }
 }
 
@@ -124,3 +127,24 @@ impl From<sled::Error> for Error {
         Error::Sled(err)
     }
 }
+
+impl From<sled::transaction::TransactionError> for Error {
+    fn from(err: sled::transaction::TransactionError) -> Error {
+        match err {
+            sled::transaction::TransactionError::Abort(e) => Error::Sled(e),
+            sled::transaction::TransactionError::Storage(e) => Error::Sled(e),
+        }
+    }
+}
+
+impl From<capnp::Error> for Error {
+    fn from(err: capnp::Error) -> Error {
+        Error::Capnp(err)
+    }
+}
+
+impl From<capnp::NotInSchema> for Error {
+    fn from(err: capnp::NotInSchema) -> Error {
+        Error::Capnp(err.into())
+    }
+}
@@ -0,0 +1,114 @@
+//! Payload kinds defined outside this crate. A kind is registered once, at
+//! startup, and from then on payloads of its type are checked when made and
+//! when shown by `get`. Every payload of a type that is not built in
+//! decodes, as `Payload::Opaque`, so a client never fails on a type or
+//! version it does not know.
+
+use crate::primitives::payloads::BUILTIN_TYPES;
+use crate::Error;
+use std::sync::{Arc, PoisonError, RwLock};
+
+/// A payload type and how to handle its encoded bodies
+pub trait PayloadKind: Send + Sync {
+    /// Names the type in encoded payloads. Pick something unlikely to clash,
+    /// such as a reverse domain name ("com.example.license").
+    fn type_id(&self) -> &str;
+
+    /// The schema version new payloads of this type are written with
+    fn version(&self) -> u16;
+
+    /// Check a body before it is stored. Versions this kind cannot read
+    /// should fail.
+    fn validate(&self, version: u16, body: &[u8]) -> Result<(), Error>;
+
+    /// The body as it is shown to the user, secret parts included
+    fn display(&self, version: u16, body: &[u8]) -> Result<String, Error>;
+}
+
+static KINDS: RwLock<Vec<Arc<dyn PayloadKind>>> = RwLock::new(Vec::new());
+
+/// Register a kind, which must not reuse a built-in or registered type id
+pub fn register(kind: Arc<dyn PayloadKind>) -> Result<(), Error> {
+    let id = kind.type_id();
+    if id.is_empty() || BUILTIN_TYPES.contains(&id) {
+        return Err(Error::Fail(format!("payload type \"{id}\" is reserved")));
+    }
+    let mut kinds = KINDS.write().unwrap_or_else(PoisonError::into_inner);
+    if kinds.iter().any(|k| k.type_id() == id) {
+        return Err(Error::Fail(format!(
+            "payload type \"{id}\" is already registered"
+        )));
+    }
+    kinds.push(kind);
+    Ok(())
+}
+
+/// The registered kind with this type id, if any
+pub fn lookup(type_id: &str) -> Option<Arc<dyn PayloadKind>> {
+    let kinds = KINDS.read().unwrap_or_else(PoisonError::into_inner);
+    kinds.iter().find(|k| k.type_id() == type_id).cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::payloads::{OpaquePayload, Payload, MAGIC};
+
+    /// A licence key, stored as text
+    struct Licence;
+
+    impl PayloadKind for Licence {
+        fn type_id(&self) -> &str {
+            "com.example.licence"
+        }
+
+        fn version(&self) -> u16 {
+            1
+        }
+
+        fn validate(&self, version: u16, body: &[u8]) -> Result<(), Error> {
+            match (version, std::str::from_utf8(body)) {
+                (1, Ok(key)) if key.starts_with("LIC-") => Ok(()),
+                _ => Err(Error::Fail("not a licence key".to_string())),
+            }
+        }
+
+        fn display(&self, _: u16, body: &[u8]) -> Result<String, Error> {
+            Ok(format!("licence: {}\n", String::from_utf8_lossy(body)))
+        }
+    }
+
+    #[test]
+    fn test_kinds() {
+        register(Arc::new(Licence)).unwrap();
+        assert!(register(Arc::new(Licence)).is_err());
+        assert!(lookup("com.example.licence").is_some());
+        assert!(lookup("com.example.other").is_none());
+
+        let licence = |body: &[u8]| {
+            OpaquePayload::new("com.example.licence", 1, body.to_vec())
+        };
+        assert!(licence(b"ABC").is_err());
+        let payload = Payload::Opaque(licence(b"LIC-123").unwrap());
+        let bytes = payload.encode().unwrap();
+        let Payload::Opaque(p) = Payload::decode(&bytes).unwrap() else {
+            panic!("not opaque");
+        };
+        assert_eq!(*p.display().unwrap(), "licence: LIC-123\n");
+
+        // Bodies written by anyone else, or by a newer version of the kind,
+        // still decode, and are checked before they are shown
+        let written = |version: u16, body: &[u8]| {
+            let bytes =
+                bincode::serialize(&("com.example.licence", version, body))
+                    .unwrap();
+            match Payload::decode(&[MAGIC, &bytes].concat()).unwrap() {
+                Payload::Opaque(p) => p,
+                _ => panic!("not opaque"),
+            }
+        };
+        assert!(written(1, b"LIC-456").display().is_ok());
+        assert!(written(1, b"ABC").display().is_err());
+        assert!(written(2, b"LIC-456").display().is_err());
+    }
+}
@@ -1,2 +1,3 @@
+pub mod kinds;
 pub mod payloads;
 pub mod secret;
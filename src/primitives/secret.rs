@@ -1,17 +1,16 @@
 use super::payloads::*;
-use crate::crypto::{NONCE_LEN, SALT_LEN};
+use crate::crypto::suite::CipherSuite;
+use crate::crypto::types::Fingerprint;
 use crate::Error;
 use argon2::{
-    password_hash::{
-        rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier,
-        SaltString,
-    },
+    password_hash::{rand_core::OsRng, PasswordHasher, SaltString},
     Argon2,
 };
+use ed25519_dalek::Signature;
 use serde::{Deserialize, Serialize};
 use std::default::Default;
+use std::fmt;
 use std::net::Ipv4Addr;
-use std::string::ToString;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// The scope of which systems are allowed to access a secret
@@ -65,9 +64,9 @@ impl SecretID {
     }
 }
 
-impl ToString for SecretID {
-    fn to_string(&self) -> String {
-        hex::encode(&self.0)
+impl fmt::Display for SecretID {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", hex::encode(&self.0))
     }
 }
 
@@ -89,17 +88,11 @@ pub struct Header {
     /// Epoch in ms of creation time
     pub creation: u128,
 
-    /// Expiration time (0 for no expiration)
+    /// Expiration time in seconds since the epoch (0 for no expiration)
     pub expiration: u64,
 
     /// The secret's scope
     pub scope: Scope,
-    // TODO
-    // A checksum for integrity
-    //sum: Vec<u8>,
-
-    // ed25519 digital signature
-    //sig: Signature,
 }
 
 impl Header {
@@ -125,19 +118,30 @@ impl Header {
 }
 
 /// The type of secret
-#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[derive(Default, Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub enum Tag {
     APIKey,
     PublicKey,
     PrivateKey,
     Keypair,
     Credentials,
+    #[default]
     Other,
+    /// A `Keypair` payload that `ssh_agent` serves to SSH
+    SshKey,
+    Certificate,
 }
 
-impl Default for Tag {
-    fn default() -> Tag {
-        Tag::Other
+impl Tag {
+    /// The payload type secrets with this tag must hold, if it names one.
+    /// The older, descriptive tags go with any payload.
+    pub fn payload_type(&self) -> Option<&'static str> {
+        match self {
+            Tag::Keypair | Tag::SshKey => Some("keypair"),
+            Tag::Credentials => Some("credentials"),
+            Tag::Certificate => Some("certificate"),
+            Tag::APIKey | Tag::PublicKey | Tag::PrivateKey | Tag::Other => None,
+        }
     }
 }
 
@@ -152,6 +156,24 @@ pub struct Secret {
     pub header: Header,
 }
 
+impl Secret {
+    /// Check that the header's tag agrees with the payload. The tag is in
+    /// the clear, and `list` and the ssh-agent go by it.
+    pub fn check_tag(&self) -> Result<(), Error> {
+        let Some(tag) = &self.header.tag else {
+            return Ok(());
+        };
+        match tag.payload_type() {
+            Some(t) if t != self.secret.type_id() => Err(Error::Fail(format!(
+                "{} is tagged {tag:?} but holds a {} payload",
+                self.header.label,
+                self.secret.type_id()
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
 /// An encrypted secret, which is what is written to fs
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct EncSecret {
@@ -160,6 +182,34 @@ pub struct EncSecret {
 
     /// The secret header (plaintext)
     pub header: Header,
+
+    /// How `secret` was encrypted
+    pub suite: CipherSuite,
+
+    /// The writer's ed25519 signature over `signed_bytes`. Secrets written
+    /// with keys that predate signing have none.
+    pub signature: Option<Signature>,
+
+    /// The key `secret` was encrypted to. Secrets written before
+    /// fingerprints have none, and belong to the server's master key.
+    pub key: Option<Fingerprint>,
+}
+
+impl EncSecret {
+    /// The bytes the signature covers: the header, suite, ciphertext and
+    /// key, which is left out when unset so older signatures still hold
+    pub fn signed_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut buf = b"spark secret\0".to_vec();
+        buf.extend(bincode::serialize(&(
+            &self.header,
+            self.suite,
+            &self.secret,
+        ))?);
+        if let Some(key) = &self.key {
+            buf.extend(key.as_bytes());
+        }
+        Ok(buf)
+    }
 }
 
 #[cfg(test)]
@@ -168,14 +218,32 @@ mod tests {
 
     #[test]
     fn test_header() {
-        let h = Header::new("my key", "my key desc", 0, Scope::Local);
+        let h =
+            Header::new("my key", Some("my key desc"), None, 0, Scope::Local);
         println!("{:?}", h);
     }
 
+    #[test]
+    fn test_tag() {
+        let secret = |payload, tag| Secret {
+            secret: payload,
+            header: Header::new("tagged", None, tag, 0, Scope::Local).unwrap(),
+        };
+        let creds =
+            || Payload::Credentials(CredentialsPayload::new("db", "app", "pw"));
+        let generic = || Payload::Generic(GenericPayload::new(vec![1]));
+
+        secret(creds(), Some(Tag::Credentials)).check_tag().unwrap();
+        secret(creds(), None).check_tag().unwrap();
+        secret(generic(), Some(Tag::APIKey)).check_tag().unwrap();
+        assert!(secret(generic(), Some(Tag::SshKey)).check_tag().is_err());
+        assert!(secret(creds(), Some(Tag::Certificate)).check_tag().is_err());
+    }
+
     #[test]
     fn test_secretid() {
-        let id = SecretID::from("label", "desc", 100).unwrap();
+        let id = SecretID::from("label", Some("desc"), 100).unwrap();
         println!("id: {:?}", id);
-        println!("string id: {}", id.to_string());
+        println!("string id: {}", id);
     }
 }
@@ -7,7 +7,13 @@ use std::error::Error;
 struct Args {
     port: u16,
     db_path: String,
-    key_path: String,
+    #[arg(
+        required = true,
+        help = "The master key of a new store, then any other keys to hold"
+    )]
+    key_paths: Vec<String>,
+    #[arg(long, help = "Create an admin user before serving")]
+    admin: Option<String>,
 }
 
 #[tokio::main]
@@ -16,7 +22,14 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
     env_logger::init();
 
-    let server =
-        server::Server::init(&args.db_path, &args.key_path, args.port)?;
+    let mut server =
+        server::Server::init(args.db_path, &args.key_paths, args.port)?;
+
+    if let Some(admin) = args.admin {
+        let password =
+            rpassword::prompt_password(format!("Password for {admin}: "))?;
+        server.add_admin(&admin, &password)?;
+    }
+
     server.serve().await
 }
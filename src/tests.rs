@@ -1,8 +1,11 @@
 #[macro_use]
 extern crate lazy_static;
 
+use spark::core::access::Identity;
 use spark::core::server;
 use spark::core::server::Protocol;
+use spark::crypto::passphrase::Env;
+use spark::crypto::policy::Policy;
 use spark::crypto::types::*;
 use spark::crypto::Encryptor;
 use spark::primitives::payloads::{GenericPayload, Payload};
@@ -11,6 +14,11 @@ use std::path::Path;
 
 pub static TESTKEY_PATH: &str = "./data/336d78316b4c.esk";
 
+/// The test key's passphrase is read from here, so these can run unattended
+fn passphrase() -> Env {
+    Env("SPARK_PASSPHRASE".to_string())
+}
+
 lazy_static! {
     static ref TEST_ID: Vec<u8> = vec![
         158, 199, 205, 249, 103, 8, 115, 109, 112, 251, 108, 133, 109, 73, 139,
@@ -20,23 +28,30 @@ lazy_static! {
 }
 
 // TODO: move all these tests to real tests with dependency injection
+#[allow(dead_code)]
 fn test_keygen() {
     // Encrypt & write a key
     let sk = ServerKey::new();
     println!("key: {:?}", sk);
     println!("keygen done");
-    sk.write_key().unwrap();
+    sk.write_key(&passphrase(), &Policy::default()).unwrap();
 }
 
+#[allow(dead_code)]
 fn test_key_unlock() {
     // Read & decrypt key
-    let dec = ServerKey::read_key(Path::new(TESTKEY_PATH)).unwrap();
+    let dec =
+        ServerKey::read_key(Path::new(TESTKEY_PATH), &passphrase()).unwrap();
     println!("decrypted: {:?}", dec);
 }
 
+#[allow(dead_code)]
 fn test_encrypter() {
-    let key: ServerKey =
-        ServerKey::read_key(Path::new("./data/4f717474396a.esk")).unwrap();
+    let key: ServerKey = ServerKey::read_key(
+        Path::new("./data/4f717474396a.esk"),
+        &passphrase(),
+    )
+    .unwrap();
 
     let secret = Secret {
         secret: Payload::Generic(GenericPayload::new(
@@ -57,7 +72,8 @@ fn test_encrypter() {
 }
 
 fn test_server() {
-    let key: ServerKey = ServerKey::read_key(Path::new(TESTKEY_PATH)).unwrap();
+    let key: ServerKey =
+        ServerKey::read_key(Path::new(TESTKEY_PATH), &passphrase()).unwrap();
 
     let secret = Secret {
         secret: Payload::Generic(GenericPayload::new(
@@ -69,10 +85,10 @@ fn test_server() {
         header: Header::new("first secret", None, None, 0, Scope::Public)
             .unwrap(),
     };
-    let enc_secret = key.encrypt(secret).unwrap();
+    let _enc_secret = key.encrypt(secret).unwrap();
 
-    let mut server =
-        server::Server::init("./data/db1", TESTKEY_PATH, 3030).unwrap();
+    let server =
+        server::Server::init("./data/db1", &[TESTKEY_PATH], 3030).unwrap();
     //store.put_secret(enc_secret).unwrap();
 
     server.print_db();
@@ -88,10 +104,11 @@ fn test_server() {
         scope: Scope::Public,
     };
 
-    let query_copy = query_header.clone();
+    let _query_copy = query_header.clone();
     let mut query_copy2 = query_header.clone();
 
-    let got_secret: EncSecret = server.get(query_header).unwrap().unwrap();
+    let got_secret: EncSecret =
+        server.get(&Identity::Local, query_header).unwrap().unwrap();
 
     println!("got secret: {got_secret:?}");
 
@@ -99,10 +116,10 @@ fn test_server() {
     println!("decrypted secret: {decrypted_secret:?}");
 
     query_copy2.creation = 0;
-    eprintln!("{:?}", server.get(query_copy2));
+    eprintln!("{:?}", server.get(&Identity::Local, query_copy2));
 
     server
-        .list()
+        .list(&Identity::Local)
         .unwrap()
         .iter()
         .enumerate()
@@ -0,0 +1,153 @@
+//! Runs the client binary against a live server, to check what it writes
+//! to stdout. The output of `render` and `gen` is meant to be redirected or
+//! piped, so nothing but the rendered template or the key may reach it.
+
+use rsa::pkcs8::DecodePublicKey;
+use spark::core::access::Op;
+use spark::core::client::Client;
+use spark::core::server::Server;
+use spark::crypto::passphrase::Env;
+use spark::crypto::types::ServerKey;
+use std::fs;
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+const PASSPHRASE_VAR: &str = "SPARK_STDOUT_TEST_PASSPHRASE";
+const PASSPHRASE: &str = "absurd wrist oyster nephew galaxy";
+
+/// A server on a free port, and a token for it in `dir`
+struct Vault {
+    dir: PathBuf,
+    port: u16,
+}
+
+impl Vault {
+    fn start(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(name);
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir(&dir).unwrap();
+
+        let key_path = dir.join("master.esk");
+        std::env::set_var(PASSPHRASE_VAR, PASSPHRASE);
+        let key = ServerKey::new()
+            .lock(&Env(PASSPHRASE_VAR.to_string()))
+            .unwrap();
+        fs::write(&key_path, key.to_bytes().unwrap()).unwrap();
+
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let mut server =
+            Server::init(dir.join("db").as_path(), &[key_path.as_path()], port)
+                .unwrap();
+        server.add_admin("root", "rootpw").unwrap();
+        thread::spawn(move || {
+            tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap()
+                .block_on(server.serve())
+                .unwrap()
+        });
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let admin = Client::init(&format!("127.0.0.1:{port}"))
+            .unwrap()
+            .login("root", "rootpw");
+        let ops = [Op::GetMaster, Op::List, Op::Get, Op::Put];
+        let token = (0..50)
+            .find_map(|_| {
+                match runtime.block_on(admin.mint_token("*", &ops, 600)) {
+                    Ok(token) => Some(token),
+                    Err(_) => {
+                        thread::sleep(Duration::from_millis(100));
+                        None
+                    }
+                }
+            })
+            .expect("server did not start");
+        fs::write(dir.join("token"), token).unwrap();
+        Self { dir, port }
+    }
+
+    /// Run the client with `args`, returning its stdout
+    fn client(&self, args: &[&str]) -> String {
+        let out = Command::new(env!("CARGO_BIN_EXE_client"))
+            .args(["--addr", &format!("127.0.0.1:{}", self.port)])
+            .arg("--token-file")
+            .arg(self.dir.join("token"))
+            .args(["--passphrase-source", &format!("env:{PASSPHRASE_VAR}")])
+            .args(args)
+            .env(PASSPHRASE_VAR, PASSPHRASE)
+            .env_remove("SPARK_TOKEN")
+            .env_remove("XDG_RUNTIME_DIR")
+            .stdin(Stdio::null())
+            .output()
+            .unwrap();
+        assert!(
+            out.status.success(),
+            "client {args:?}: {}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+        String::from_utf8(out.stdout).unwrap()
+    }
+}
+
+impl Drop for Vault {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+#[test]
+fn test_render_stdout() {
+    let vault = Vault::start("spark_test_render");
+    let put = ["put", "db-prod", "local", "credentials", "app", "hunter2"];
+    assert_eq!(vault.client(&put), "");
+
+    let template_path = vault.dir.join("app.conf.tmpl");
+    fs::write(
+        &template_path,
+        "user = {{ spark \"db-prod\" \"username\" }}\n\
+         password = {{ spark \"db-prod\" \"password\" }}\n",
+    )
+    .unwrap();
+    let rendered = vault.client(&["render", template_path.to_str().unwrap()]);
+    assert_eq!(rendered, "user = app\npassword = hunter2\n");
+}
+
+#[test]
+fn test_gen_stdout() {
+    let vault = Vault::start("spark_test_gen");
+    let gen = |name, key_type, ssh: bool| {
+        let mut args =
+            vec!["gen", "keypair", name, "local", "--type", key_type];
+        if ssh {
+            args.push("--ssh");
+        }
+        vault.client(&args)
+    };
+
+    let hex_key = gen("signing", "ed25519", false);
+    assert_eq!(hex_key.len(), 65);
+    assert_eq!(hex::decode(hex_key.trim_end()).unwrap().len(), 32);
+
+    let pem_key = gen("tls", "rsa", false);
+    assert!(pem_key.starts_with("-----BEGIN PUBLIC KEY-----\n"));
+    assert!(pem_key.ends_with("-----END PUBLIC KEY-----\n"));
+    assert!(rsa::RsaPublicKey::from_public_key_pem(&pem_key).is_ok());
+
+    let line = gen("login", "ed25519", true);
+    assert!(line.starts_with("ssh-ed25519 "));
+    assert_eq!(line.lines().count(), 1);
+
+    assert_eq!(gen("aes", "aes", false), "");
+}